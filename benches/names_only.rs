@@ -0,0 +1,42 @@
+//! Compares full parsing against `ParseConfig::names_only` on a
+//! device-heavy fixture, to guard the skip-subtree fast path.
+
+use std::io::Write;
+
+use criterion::{criterion_group, criterion_main, Criterion};
+
+/// Builds a synthetic, device-heavy `.als`-shaped XML document in memory so
+/// the benchmark doesn't depend on a committed binary fixture.
+fn device_heavy_fixture(track_count: usize) -> Vec<u8> {
+    let mut xml = String::from("<Ableton><Tracks>");
+    for i in 0..track_count {
+        xml.push_str(&format!(
+            "<AudioTrack><Id Value=\"{i}\"/><Name><EffectiveName Value=\"Track {i}\"/></Name>\
+             <DeviceChain><Mixer><Volume Value=\"1.0\"/></Mixer>\
+             <AudioEffectBranch><Name><EffectiveName Value=\"Eq8\"/></Name></AudioEffectBranch>\
+             </DeviceChain></AudioTrack>"
+        ));
+    }
+    xml.push_str("</Tracks></Ableton>");
+
+    let mut gz = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::fast());
+    gz.write_all(xml.as_bytes()).unwrap();
+    gz.finish().unwrap()
+}
+
+fn bench_full_vs_names_only(c: &mut Criterion) {
+    let fixture = device_heavy_fixture(200);
+    let path = std::env::temp_dir().join("als_parser_rs_bench_fixture.als");
+    std::fs::write(&path, &fixture).unwrap();
+    let path = path.to_str().unwrap();
+
+    c.bench_function("parse_full", |b| b.iter(|| als_parser_rs::get_project_from_als(path)));
+
+    let names_only = als_parser_rs::ParseConfig { names_only: true };
+    c.bench_function("parse_names_only", |b| {
+        b.iter(|| als_parser_rs::parse_project_with_config(path, &names_only))
+    });
+}
+
+criterion_group!(benches, bench_full_vs_names_only);
+criterion_main!(benches);
@@ -0,0 +1,79 @@
+//! Parallel batch parsing over directories of `.als` files, for library
+//! browsers indexing large collections of sets.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::error::ParseError;
+use crate::model::Project;
+use crate::parse::get_project_from_als;
+
+/// Parses every `.als` file directly inside `dir` (not recursing into
+/// subdirectories), one scoped thread per file. A file that fails to parse
+/// has its error captured alongside its path rather than aborting the rest
+/// of the batch. Returns an empty vec if `dir` can't be read.
+pub fn parse_directory(dir: &Path) -> Vec<(PathBuf, Result<Project, ParseError>)> {
+    parse_paths(find_als_files(dir, false))
+}
+
+/// Like [`parse_directory`], but recurses into subdirectories.
+pub fn parse_directory_recursive(dir: &Path) -> Vec<(PathBuf, Result<Project, ParseError>)> {
+    parse_paths(find_als_files(dir, true))
+}
+
+/// Collects `.als` file paths under `dir`, recursing when `recursive` is
+/// `true`. Unreadable directories are skipped rather than failing the whole
+/// walk, matching the batch functions' "capture per-file, don't abort"
+/// contract.
+fn find_als_files(dir: &Path, recursive: bool) -> Vec<PathBuf> {
+    let mut files = Vec::new();
+    let Ok(entries) = fs::read_dir(dir) else {
+        return files;
+    };
+
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if path.is_dir() {
+            if recursive {
+                files.extend(find_als_files(&path, recursive));
+            }
+        } else if path.extension().is_some_and(|ext| ext.eq_ignore_ascii_case("als")) {
+            files.push(path);
+        }
+    }
+
+    files
+}
+
+/// Caps how many files are parsed concurrently. A library browser indexing a
+/// collection of thousands of sets would otherwise spawn one OS thread per
+/// file; chunking to roughly the machine's core count keeps thread count
+/// bounded without pulling in a thread-pool dependency.
+fn max_concurrency() -> usize {
+    std::thread::available_parallelism().map(|n| n.get()).unwrap_or(4)
+}
+
+/// Parses `paths` in parallel, one scoped thread per file within a
+/// [`max_concurrency`]-sized chunk at a time.
+fn parse_paths(paths: Vec<PathBuf>) -> Vec<(PathBuf, Result<Project, ParseError>)> {
+    let chunk_size = max_concurrency();
+    let mut results = Vec::with_capacity(paths.len());
+
+    for chunk in paths.chunks(chunk_size) {
+        std::thread::scope(|scope| {
+            let handles: Vec<_> = chunk
+                .iter()
+                .map(|path| {
+                    scope.spawn(move || {
+                        let result = get_project_from_als(&path.to_string_lossy());
+                        (path.clone(), result)
+                    })
+                })
+                .collect();
+
+            results.extend(handles.into_iter().map(|handle| handle.join().expect("parse thread panicked")));
+        });
+    }
+
+    results
+}
@@ -0,0 +1,56 @@
+//! In-memory cache for parsed `.als` projects, so an editor re-running a
+//! diff against the same file (e.g. after every save) skips decompression
+//! and re-parsing when the file hasn't actually changed.
+
+use std::collections::HashMap;
+use std::fs;
+use std::time::SystemTime;
+
+use crate::error::ParseError;
+use crate::model::Project;
+use crate::parse::get_project_from_als;
+
+/// A file is considered unchanged as long as its modified time and byte
+/// length both match the last parse. Cheaper than hashing the file's
+/// contents, and good enough to catch the common "nothing changed" case.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct CacheKey {
+    modified: Option<SystemTime>,
+    len: u64,
+}
+
+/// Caches parsed [`Project`]s keyed by `(path, mtime, len)`. A
+/// [`ParserCache::get_or_parse`] call for a path whose mtime/len match the
+/// last parse returns the cached [`Project`] without touching the file's
+/// contents again; anything else (first call, or a changed file) parses
+/// normally and refreshes the entry.
+#[derive(Debug, Default)]
+pub struct ParserCache {
+    entries: HashMap<String, (CacheKey, Project)>,
+}
+
+impl ParserCache {
+    pub fn new() -> Self {
+        ParserCache::default()
+    }
+
+    /// Parses `path`, reusing a cached [`Project`] if the file's mtime and
+    /// length are unchanged since the last call for this path.
+    pub fn get_or_parse(&mut self, path: &str) -> Result<Project, ParseError> {
+        let metadata = fs::metadata(path)?;
+        let key = CacheKey {
+            modified: metadata.modified().ok(),
+            len: metadata.len(),
+        };
+
+        if let Some((cached_key, project)) = self.entries.get(path) {
+            if *cached_key == key {
+                return Ok(project.clone());
+            }
+        }
+
+        let project = get_project_from_als(path)?;
+        self.entries.insert(path.to_string(), (key, project.clone()));
+        Ok(project)
+    }
+}
@@ -0,0 +1,106 @@
+//! Structured, machine-applicable changes, as an alternative to the
+//! human-readable lines produced by [`crate::diff`].
+
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+use crate::model::{Project, Track};
+
+/// A single change between two [`Project`]s, granular enough for a caller to
+/// apply without re-parsing the whole `.als` file, or to render without
+/// string-matching human prose.
+///
+/// This only covers what [`Project::apply`] knows how to apply and what
+/// [`Project::diff_structured`](crate::diff) produces today.
+/// Device-level detail (compressor thresholds, groove assignments, and the
+/// rest of what `Project::diff`'s prose lines cover) isn't represented as
+/// its own variant yet — `RackModified` stands in for all of it, the same
+/// way the prose diff's "Modified internal Rack devices" line does.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum Change {
+    TrackAdded { track: Track },
+    TrackRemoved { id: String },
+    TrackRenamed { id: String, from: Option<String>, to: Option<String> },
+    InstrumentSwapped { id: String, from: String, to: String },
+    RackModified { track: String },
+    MetronomeChanged { metronome: Option<bool> },
+    CountInChanged { count_in: Option<i32> },
+}
+
+/// A [`Change`] that [`Project::apply`] couldn't apply.
+#[derive(Debug)]
+pub struct ApplyError {
+    pub change: Change,
+    pub reason: String,
+}
+
+impl fmt::Display for ApplyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "could not apply {:?}: {}", self.change, self.reason)
+    }
+}
+
+impl std::error::Error for ApplyError {}
+
+impl Project {
+    /// Applies `changes` to this project in memory, for previewing a
+    /// JSON-patch-style update without re-parsing the source `.als` file.
+    ///
+    /// Stops at the first change it can't apply (e.g. renaming a track that
+    /// no longer exists) and returns an error rather than silently no-op'ing
+    /// the rest.
+    pub fn apply(&mut self, changes: &[Change]) -> Result<(), ApplyError> {
+        for change in changes {
+            match change {
+                Change::TrackAdded { track } => {
+                    self.tracks.push(track.clone());
+                }
+                Change::TrackRemoved { id } => {
+                    let before = self.tracks.len();
+                    self.tracks.retain(|t| &t.id != id);
+                    if self.tracks.len() == before {
+                        return Err(ApplyError {
+                            change: change.clone(),
+                            reason: format!("no track with id {id}"),
+                        });
+                    }
+                }
+                Change::TrackRenamed { id, from, to } => {
+                    let track = self.tracks.iter_mut().find(|t| &t.id == id).ok_or_else(|| ApplyError {
+                        change: change.clone(),
+                        reason: format!("no track with id {id}"),
+                    })?;
+                    // `diff_structured` emits this both for a `user_name` change
+                    // and for a display-name change; a `from` that matches the
+                    // display name rather than `user_name` marks the latter.
+                    if track.user_name != *from && from.as_deref() == Some(track.effective_name.as_str()) {
+                        track.effective_name = to.clone().unwrap_or_default();
+                    } else {
+                        track.user_name = to.clone();
+                    }
+                }
+                Change::InstrumentSwapped { id, to, .. } => {
+                    let track = self.tracks.iter_mut().find(|t| &t.id == id).ok_or_else(|| ApplyError {
+                        change: change.clone(),
+                        reason: format!("no track with id {id}"),
+                    })?;
+                    track.effective_name = to.clone();
+                }
+                Change::RackModified { .. } => {
+                    return Err(ApplyError {
+                        change: change.clone(),
+                        reason: "rack device edits are too granular to apply structurally".to_string(),
+                    });
+                }
+                Change::MetronomeChanged { metronome } => {
+                    self.metronome = *metronome;
+                }
+                Change::CountInChanged { count_in } => {
+                    self.count_in = *count_in;
+                }
+            }
+        }
+        Ok(())
+    }
+}
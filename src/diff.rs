@@ -0,0 +1,1066 @@
+//! Human-readable diffing between two parsed [`Project`]s.
+
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::change::Change;
+use crate::model::{Branch, Clip, ClipType, CrossfadeAssign, Project, Scene, Track};
+
+/// Headline counters tallied from a diff's structured [`Change`] list, for a
+/// dashboard that wants "3 tracks added, 1 removed, 5 modified" without
+/// parsing prose or counting `Change` variants itself.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DiffStats {
+    pub added: usize,
+    pub removed: usize,
+    pub renamed: usize,
+    pub instrument_swaps: usize,
+    pub racks_modified: usize,
+}
+
+/// Tunables for `Project::diff_with_options`.
+#[derive(Debug, Clone, Copy)]
+pub struct DiffOptions {
+    /// When `true`, purely cosmetic state (e.g. a rack chain's expanded/
+    /// collapsed layout) is included in the diff. Defaults to `false`.
+    pub include_cosmetic: bool,
+    /// When `true`, an added track and a removed track that share an
+    /// effective name are collapsed into a single "replaced" line instead
+    /// of being reported as an unrelated add+remove pair. Useful when a
+    /// track's `Id` churns across a save but the track itself didn't change.
+    pub coalesce_replacements: bool,
+    /// When `true`, a changed `Project.last_modified` is reported. Defaults
+    /// to `false` since this field changes on essentially every save and
+    /// would otherwise dominate the diff.
+    pub include_timestamps: bool,
+    /// How many levels of nested racks `diff_branch_lists` will descend
+    /// into before giving up on a branch, reporting `Branch X: nesting too
+    /// deep to diff` instead of recursing further. Guards against a
+    /// pathological or malicious file blowing the stack. Defaults to 64,
+    /// far beyond anything Live's own rack UI lets a user build by hand.
+    pub max_branch_depth: usize,
+}
+
+impl Default for DiffOptions {
+    fn default() -> Self {
+        DiffOptions {
+            include_cosmetic: false,
+            coalesce_replacements: false,
+            include_timestamps: false,
+            max_branch_depth: 64,
+        }
+    }
+}
+
+/// Cheap, allocation-light tallies for a quick "N changes" badge. Walks the
+/// track maps once and counts without building a single `Change`/`String`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct DiffCounts {
+    pub added: usize,
+    pub removed: usize,
+    pub modified: usize,
+}
+
+impl Project {
+    /// Produces a list of human-readable change lines between `old` and
+    /// `self`. Line order is stable across repeated calls on the same
+    /// inputs: tracks are walked in their `Vec` order and matched by id via
+    /// a `BTreeMap`, never a hash-ordered collection.
+    pub fn diff(&self, old: &Project) -> Vec<String> {
+        self.diff_with_options(old, &DiffOptions::default())
+    }
+
+    /// Tallies added/removed/modified tracks without allocating a `Change`
+    /// or message per track. Prefer this over `diff`/`diff_structured` for
+    /// hot-path UI badges that only need the counts.
+    pub fn diff_summary_counts(&self, old: &Project) -> DiffCounts {
+        let old_map: BTreeMap<&str, &Track> = old.tracks.iter().map(|t| (t.id.as_str(), t)).collect();
+        let new_map: BTreeMap<&str, &Track> = self.tracks.iter().map(|t| (t.id.as_str(), t)).collect();
+
+        let mut counts = DiffCounts::default();
+
+        for id in old_map.keys() {
+            if !new_map.contains_key(id) {
+                counts.removed += 1;
+            }
+        }
+
+        for (id, track) in &new_map {
+            match old_map.get(id) {
+                None => counts.added += 1,
+                Some(old_track) => {
+                    if track != old_track {
+                        counts.modified += 1;
+                    }
+                }
+            }
+        }
+
+        counts
+    }
+
+    /// Tallies [`Change`] variants from [`Project::diff_structured`] in one
+    /// pass, for a numeric summary alongside the prose/structured diff.
+    pub fn diff_stats(&self, old: &Project) -> DiffStats {
+        let mut stats = DiffStats::default();
+        for change in self.diff_structured(old) {
+            match change {
+                Change::TrackAdded { .. } => stats.added += 1,
+                Change::TrackRemoved { .. } => stats.removed += 1,
+                Change::TrackRenamed { .. } => stats.renamed += 1,
+                Change::InstrumentSwapped { .. } => stats.instrument_swaps += 1,
+                Change::RackModified { .. } => stats.racks_modified += 1,
+                Change::MetronomeChanged { .. } | Change::CountInChanged { .. } => {}
+            }
+        }
+        stats
+    }
+
+    /// Like [`Project::diff`], but lets the caller opt into cosmetic-only changes.
+    pub fn diff_with_options(&self, old: &Project, opts: &DiffOptions) -> Vec<String> {
+        let mut changes = Vec::new();
+
+        if self.metronome != old.metronome {
+            changes.push(format!(
+                "Metronome changed from {:?} to {:?}",
+                old.metronome, self.metronome
+            ));
+        }
+
+        if self.count_in != old.count_in {
+            changes.push(format!(
+                "Count-in changed from {:?} to {:?}",
+                old.count_in, self.count_in
+            ));
+        }
+
+        if self.tempo != old.tempo {
+            changes.push(format!(
+                "Tempo changed from {} to {}",
+                old.tempo.map_or("?".to_string(), |t| t.to_string()),
+                self.tempo.map_or("?".to_string(), |t| t.to_string())
+            ));
+        }
+
+        if self.tempo_automated != old.tempo_automated {
+            changes.push(if self.tempo_automated {
+                "Tempo automation added".to_string()
+            } else {
+                "Tempo automation removed".to_string()
+            });
+        }
+
+        if self.time_signature != old.time_signature {
+            changes.push(format!(
+                "Time signature changed from {:?} to {:?}",
+                old.time_signature, self.time_signature
+            ));
+        }
+
+        if opts.include_timestamps && self.last_modified != old.last_modified {
+            changes.push(format!(
+                "Last modified changed from {:?} to {:?}",
+                old.last_modified, self.last_modified
+            ));
+        }
+
+        for surface in &old.control_surfaces {
+            if !self.control_surfaces.contains(surface) {
+                changes.push(format!("Removed control surface '{surface}'"));
+            }
+        }
+        for surface in &self.control_surfaces {
+            if !old.control_surfaces.contains(surface) {
+                changes.push(format!("Added control surface '{surface}'"));
+            }
+        }
+
+        for locator in &old.locators {
+            if !self.locators.contains(locator) {
+                changes.push(format!("Removed locator '{}' at {}", locator.name, locator.time));
+            }
+        }
+        for locator in &self.locators {
+            if !old.locators.contains(locator) {
+                changes.push(format!("Added locator '{}' at {}", locator.name, locator.time));
+            }
+        }
+
+        for groove in &old.grooves {
+            if !self.grooves.contains(groove) {
+                changes.push(format!("Removed groove '{groove}'"));
+            }
+        }
+        for groove in &self.grooves {
+            if !old.grooves.contains(groove) {
+                changes.push(format!("Added groove '{groove}'"));
+            }
+        }
+
+        match (&self.master, &old.master) {
+            (Some(new_master), Some(old_master)) => {
+                for line in new_master.diff_content_with_options(old_master, opts) {
+                    changes.push(format!("Master: {}", strip_track_label(&line, &new_master.id, &new_master.effective_name)));
+                }
+            }
+            (Some(_), None) => changes.push("Master track added".to_string()),
+            (None, Some(_)) => changes.push("Master track removed".to_string()),
+            (None, None) => {}
+        }
+
+        changes.extend(diff_scene_changes(&old.scenes, &self.scenes));
+
+        let old_map: BTreeMap<&str, &Track> = old.tracks.iter().map(|t| (t.id.as_str(), t)).collect();
+        let new_map: BTreeMap<&str, &Track> = self.tracks.iter().map(|t| (t.id.as_str(), t)).collect();
+
+        for track in &old.tracks {
+            if !new_map.contains_key(track.id.as_str()) {
+                changes.push(format!("Removed track: {}", track.effective_name));
+            }
+        }
+
+        for track in &self.tracks {
+            match old_map.get(track.id.as_str()) {
+                None => changes.push(format!("Added new track: {}", track.effective_name)),
+                Some(old_track) => {
+                    changes.extend(track.diff_content_with_options(old_track, opts));
+                    if track.group_id != old_track.group_id {
+                        match track.group_id.as_deref().and_then(|id| new_map.get(id)) {
+                            Some(group) => changes.push(format!(
+                                "Track {}: moved into group {}",
+                                track.effective_name, group.effective_name
+                            )),
+                            None => changes.push(format!("Track {}: moved out of group", track.effective_name)),
+                        }
+                    }
+                }
+            }
+        }
+
+        changes.extend(diff_track_order(old, self));
+
+        if opts.coalesce_replacements {
+            coalesce_replacements(&mut changes);
+        }
+
+        changes
+    }
+
+    /// Like [`Project::diff`], but produces structured [`Change`]s instead of
+    /// pre-joined prose, so callers can apply or render them without
+    /// string-matching. Covers what [`Project::apply`](crate::Project::apply)
+    /// knows how to apply; finer device-level detail collapses into a single
+    /// `RackModified`, mirroring the "Modified internal Rack devices" prose line.
+    pub fn diff_structured(&self, old: &Project) -> Vec<Change> {
+        let mut changes = Vec::new();
+
+        if self.metronome != old.metronome {
+            changes.push(Change::MetronomeChanged { metronome: self.metronome });
+        }
+
+        if self.count_in != old.count_in {
+            changes.push(Change::CountInChanged { count_in: self.count_in });
+        }
+
+        let old_map: BTreeMap<&str, &Track> = old.tracks.iter().map(|t| (t.id.as_str(), t)).collect();
+        let new_map: BTreeMap<&str, &Track> = self.tracks.iter().map(|t| (t.id.as_str(), t)).collect();
+
+        for track in &old.tracks {
+            if !new_map.contains_key(track.id.as_str()) {
+                changes.push(Change::TrackRemoved { id: track.id.clone() });
+            }
+        }
+
+        for track in &self.tracks {
+            match old_map.get(track.id.as_str()) {
+                None => changes.push(Change::TrackAdded { track: track.clone() }),
+                Some(old_track) => {
+                    if track.user_name != old_track.user_name {
+                        changes.push(Change::TrackRenamed {
+                            id: track.id.clone(),
+                            from: old_track.user_name.clone(),
+                            to: track.user_name.clone(),
+                        });
+                    }
+                    if track.effective_name != old_track.effective_name {
+                        let device_identity_changed = track.devices != old_track.devices
+                            || track.branches.first().map(|b| &b.branch_type)
+                                != old_track.branches.first().map(|b| &b.branch_type);
+                        if device_identity_changed {
+                            changes.push(Change::InstrumentSwapped {
+                                id: track.id.clone(),
+                                from: old_track.effective_name.clone(),
+                                to: track.effective_name.clone(),
+                            });
+                        } else {
+                            changes.push(Change::TrackRenamed {
+                                id: track.id.clone(),
+                                from: Some(old_track.effective_name.clone()),
+                                to: Some(track.effective_name.clone()),
+                            });
+                        }
+                    }
+                    if strip_cosmetic(&old_track.branches) != strip_cosmetic(&track.branches) {
+                        changes.push(Change::RackModified { track: track.id.clone() });
+                    }
+                }
+            }
+        }
+
+        changes
+    }
+
+    /// Renders a diff as a Markdown report for pasting into a PR-style review
+    /// tool: `### Added Tracks`, `### Removed Tracks`, and `### Modified
+    /// Tracks` sections, each a bullet list with bolded track names. A
+    /// modified track's own prose diff lines (routing, renames, rack changes,
+    /// ...) nest underneath it as sub-bullets.
+    pub fn diff_markdown(&self, old: &Project) -> String {
+        self.diff_markdown_with_options(old, &DiffOptions::default())
+    }
+
+    /// Like [`Project::diff_markdown`], but lets the caller opt into
+    /// cosmetic-only changes.
+    pub fn diff_markdown_with_options(&self, old: &Project, opts: &DiffOptions) -> String {
+        let old_map: BTreeMap<&str, &Track> = old.tracks.iter().map(|t| (t.id.as_str(), t)).collect();
+        let new_map: BTreeMap<&str, &Track> = self.tracks.iter().map(|t| (t.id.as_str(), t)).collect();
+
+        let mut added = Vec::new();
+        let mut removed = Vec::new();
+        let mut modified: Vec<(String, Vec<String>)> = Vec::new();
+
+        for track in &old.tracks {
+            if !new_map.contains_key(track.id.as_str()) {
+                removed.push(track.effective_name.clone());
+            }
+        }
+
+        for track in &self.tracks {
+            match old_map.get(track.id.as_str()) {
+                None => added.push(track.effective_name.clone()),
+                Some(old_track) => {
+                    let lines = track.diff_content_with_options(old_track, opts);
+                    if !lines.is_empty() {
+                        let sub_bullets: Vec<String> = lines
+                            .iter()
+                            .map(|line| strip_track_label(line, &track.id, &track.effective_name).to_string())
+                            .collect();
+                        modified.push((track.effective_name.clone(), sub_bullets));
+                    }
+                }
+            }
+        }
+
+        let mut out = String::new();
+        write_markdown_section(
+            &mut out,
+            "Added Tracks",
+            added.iter().map(|name| (name.as_str(), None::<&[String]>)),
+        );
+        write_markdown_section(
+            &mut out,
+            "Removed Tracks",
+            removed.iter().map(|name| (name.as_str(), None::<&[String]>)),
+        );
+        write_markdown_section(
+            &mut out,
+            "Modified Tracks",
+            modified.iter().map(|(name, lines)| (name.as_str(), Some(lines.as_slice()))),
+        );
+
+        out
+    }
+}
+
+/// Writes one `### {heading}` Markdown section: a bulleted, bolded entry per
+/// `(name, sub_bullets)` pair, with `sub_bullets` (when present) nested
+/// underneath as an indented sub-list.
+fn write_markdown_section<'a>(
+    out: &mut String,
+    heading: &str,
+    entries: impl Iterator<Item = (&'a str, Option<&'a [String]>)>,
+) {
+    out.push_str(&format!("### {heading}\n"));
+    let mut wrote_entry = false;
+    for (name, sub_bullets) in entries {
+        wrote_entry = true;
+        out.push_str(&format!("- **{name}**\n"));
+        for line in sub_bullets.unwrap_or_default() {
+            out.push_str(&format!("  - {line}\n"));
+        }
+    }
+    if !wrote_entry {
+        out.push_str("- _none_\n");
+    }
+    out.push('\n');
+}
+
+/// Strips a `diff_content`/`diff_content_with_options` line's leading
+/// `Track {id}: `/`Track {name}: ` label, since the track name is already the
+/// enclosing bullet; lines without that label (e.g. nested `Branch ...:`
+/// rack lines) pass through unchanged.
+fn strip_track_label<'a>(line: &'a str, id: &str, name: &str) -> &'a str {
+    line.strip_prefix(&format!("Track {id}: "))
+        .or_else(|| line.strip_prefix(&format!("Track {name}: ")))
+        .unwrap_or(line)
+}
+
+/// Reports position changes for tracks that exist in both `old` and `new`,
+/// keyed by `id` so adds/removes elsewhere in the list don't produce
+/// spurious moves. Positions are 1-based to match how the rest of the diff
+/// lines read.
+fn diff_track_order(old: &Project, new: &Project) -> Vec<String> {
+    let old_positions: BTreeMap<&str, usize> = old.tracks.iter().enumerate().map(|(i, t)| (t.id.as_str(), i)).collect();
+    let new_positions: BTreeMap<&str, usize> = new.tracks.iter().enumerate().map(|(i, t)| (t.id.as_str(), i)).collect();
+    // Relative order among the tracks on both sides decides what moved.
+    let old_shared: Vec<&str> = old.tracks.iter().map(|t| t.id.as_str()).filter(|id| new_positions.contains_key(id)).collect();
+    let new_shared: Vec<&str> = new.tracks.iter().map(|t| t.id.as_str()).filter(|id| old_positions.contains_key(id)).collect();
+
+    let mut changes = Vec::new();
+    for track in &new.tracks {
+        if let (Some(&old_pos), Some(&new_pos)) =
+            (old_positions.get(track.id.as_str()), new_positions.get(track.id.as_str()))
+        {
+            let id = track.id.as_str();
+            if old_shared.iter().position(|s| *s == id) != new_shared.iter().position(|s| *s == id) {
+                changes.push(format!(
+                    "Track {} moved from position {} to position {}",
+                    track.id,
+                    old_pos + 1,
+                    new_pos + 1
+                ));
+            }
+        }
+    }
+    changes
+}
+
+/// Reports scene changes by position, since scenes (unlike tracks) have no
+/// stable id of their own in the model — a scene's row index is the closest
+/// thing. Extra scenes past the shorter side's length are reported as added
+/// or removed rather than misread as renames of a scene that never existed.
+fn diff_scene_changes(old: &[Scene], new: &[Scene]) -> Vec<String> {
+    let mut changes = Vec::new();
+    let common = old.len().min(new.len());
+
+    for i in 0..common {
+        if old[i].name != new[i].name {
+            changes.push(format!("Scene {} renamed", i + 1));
+        }
+        if old[i].tempo != new[i].tempo {
+            changes.push(format!(
+                "Scene {} tempo changed from {:?} to {:?}",
+                i + 1,
+                old[i].tempo,
+                new[i].tempo
+            ));
+        }
+        if old[i].color != new[i].color {
+            changes.push(format!("Scene {} color changed", i + 1));
+        }
+    }
+
+    for scene in &new[common..] {
+        changes.push(format!("Added scene '{}'", scene.name));
+    }
+    for _ in &old[common..] {
+        changes.push("Removed scene".to_string());
+    }
+
+    changes
+}
+
+/// Collapses a `Removed track: X` / `Added new track: X` pair sharing the
+/// same effective name into a single `Track replaced: X` line.
+fn coalesce_replacements(changes: &mut Vec<String>) {
+    let mut removed_names = Vec::new();
+    let mut added_names = Vec::new();
+
+    for change in changes.iter() {
+        if let Some(name) = change.strip_prefix("Removed track: ") {
+            removed_names.push(name.to_string());
+        } else if let Some(name) = change.strip_prefix("Added new track: ") {
+            added_names.push(name.to_string());
+        }
+    }
+
+    let mut replaced = Vec::new();
+    for name in removed_names {
+        if let Some(pos) = added_names.iter().position(|n| *n == name) {
+            added_names.remove(pos);
+            replaced.push(name);
+        }
+    }
+
+    if replaced.is_empty() {
+        return;
+    }
+
+    changes.retain(|change| {
+        let is_removed_replaced = change
+            .strip_prefix("Removed track: ")
+            .is_some_and(|name| replaced.contains(&name.to_string()));
+        let is_added_replaced = change
+            .strip_prefix("Added new track: ")
+            .is_some_and(|name| replaced.contains(&name.to_string()));
+        !is_removed_replaced && !is_added_replaced
+    });
+
+    for name in replaced {
+        changes.push(format!("Track replaced: {name}"));
+    }
+}
+
+impl Track {
+    /// Compares this track against its previous version, returning one line per change.
+    pub fn diff_content(&self, old: &Track) -> Vec<String> {
+        self.diff_content_with_options(old, &DiffOptions::default())
+    }
+
+    /// Like [`Track::diff_content`], but lets the caller opt into cosmetic-only changes.
+    pub fn diff_content_with_options(&self, old: &Track, opts: &DiffOptions) -> Vec<String> {
+        let mut changes = Vec::new();
+
+        if self.user_name != old.user_name {
+            changes.push(format!(
+                "Track {}: renamed from {:?} to {:?}",
+                self.id, old.user_name, self.user_name
+            ));
+        }
+        if self.effective_name != old.effective_name {
+            let device_identity_changed = self.devices != old.devices
+                || self.branches.first().map(|b| &b.branch_type) != old.branches.first().map(|b| &b.branch_type);
+            if device_identity_changed {
+                changes.push(format!(
+                    "Track {}: Instrument swap from {} to {}",
+                    self.id, old.effective_name, self.effective_name
+                ));
+            } else {
+                changes.push(format!(
+                    "Track {}: renamed from {} to {}",
+                    self.id, old.effective_name, self.effective_name
+                ));
+            }
+        }
+
+        changes.extend(diff_branch_lists(&self.id, &old.branches, &self.branches, opts));
+
+        if self.sends_only != old.sends_only && self.sends_only == Some(true) {
+            changes.push(format!("Track {}: output set to Sends Only", self.id));
+        }
+
+        if self.output_routing != old.output_routing {
+            changes.push(format!(
+                "Track {}: output routing changed from {} to {}",
+                self.effective_name,
+                format_routing(&old.output_routing),
+                format_routing(&self.output_routing)
+            ));
+        }
+
+        if self.input_routing != old.input_routing {
+            changes.push(format!(
+                "Track {}: input routing changed from {} to {}",
+                self.effective_name,
+                format_routing(&old.input_routing),
+                format_routing(&self.input_routing)
+            ));
+        }
+
+        if self.crossfade != old.crossfade {
+            changes.push(format!(
+                "Track {}: crossfade assignment changed from {} to {}",
+                self.effective_name,
+                format_crossfade(old.crossfade),
+                format_crossfade(self.crossfade)
+            ));
+        }
+
+        let old_delay_ms = old.track_delay.filter(|&d| d != 0.0);
+        let new_delay_ms = self.track_delay.filter(|&d| d != 0.0);
+        if old_delay_ms != new_delay_ms {
+            let unit = if self.delay_is_samples == Some(true) { "samples" } else { "ms" };
+            changes.push(format!(
+                "Track {}: track delay changed from {}{unit} to {}{unit}",
+                self.effective_name,
+                old_delay_ms.map_or("0".to_string(), |d| d.to_string()),
+                new_delay_ms.map_or("0".to_string(), |d| d.to_string())
+            ));
+        }
+
+        if self.comp_sources != old.comp_sources {
+            changes.push(format!(
+                "Track {}: comp sources changed from {:?} to {:?}",
+                self.id, old.comp_sources, self.comp_sources
+            ));
+        }
+
+        if self.pdc_enabled != old.pdc_enabled {
+            changes.push(format!(
+                "Track {}: delay compensation changed from {:?} to {:?}",
+                self.id, old.pdc_enabled, self.pdc_enabled
+            ));
+        }
+
+        if self.muted != old.muted {
+            changes.push(format!(
+                "Track {}: {}",
+                self.id,
+                if self.muted == Some(true) { "muted" } else { "unmuted" }
+            ));
+        }
+
+        if self.soloed != old.soloed {
+            changes.push(format!(
+                "Track {}: {}",
+                self.id,
+                if self.soloed == Some(true) { "soloed" } else { "unsoloed" }
+            ));
+        }
+
+        if self.armed != old.armed {
+            changes.push(format!(
+                "Track {}: {}",
+                self.id,
+                if self.armed == Some(true) { "armed" } else { "disarmed" }
+            ));
+        }
+
+        if !self.automated_params.is_empty() && old.automated_params.is_empty() {
+            changes.push(format!("Track {}: added automation", self.effective_name));
+        } else if self.automated_params.is_empty() && !old.automated_params.is_empty() {
+            changes.push(format!("Track {}: removed automation", self.effective_name));
+        }
+
+        if self.frozen != old.frozen {
+            changes.push(format!(
+                "Track {}: {}",
+                self.effective_name,
+                if self.frozen == Some(true) { "frozen" } else { "unfrozen" }
+            ));
+        }
+
+        if self.color != old.color {
+            changes.push(format!("Track {}: color changed", self.effective_name));
+        }
+
+        if self.comment != old.comment {
+            changes.push(format!("Track {}: comment changed", self.effective_name));
+        }
+
+        if self.volume != old.volume {
+            changes.push(format!(
+                "Track {}: volume changed from {} to {}",
+                self.effective_name,
+                old.volume.map_or("?".to_string(), |v| format!("{v:.2}")),
+                self.volume.map_or("?".to_string(), |v| format!("{v:.2}"))
+            ));
+        }
+
+        if self.pan != old.pan {
+            changes.push(format!(
+                "Track {}: pan changed from {} to {}",
+                self.effective_name,
+                old.pan.map_or("?".to_string(), |v| format!("{v:.2}")),
+                self.pan.map_or("?".to_string(), |v| format!("{v:.2}"))
+            ));
+        }
+
+        for device in &old.devices {
+            if !self.devices.contains(device) {
+                changes.push(format!("Track {}: removed {device}", self.effective_name));
+            }
+        }
+        for device in &self.devices {
+            if !old.devices.contains(device) {
+                changes.push(format!("Track {}: added {device}", self.effective_name));
+            }
+        }
+        if self.devices != old.devices {
+            let mut old_sorted = old.devices.clone();
+            let mut new_sorted = self.devices.clone();
+            old_sorted.sort();
+            new_sorted.sort();
+            if old_sorted == new_sorted {
+                changes.push(format!("Track {}: reordered devices", self.effective_name));
+            }
+        }
+
+        for send in &self.sends {
+            match old.sends.iter().find(|s| s.target_index == send.target_index) {
+                None => changes.push(format!(
+                    "Track {}: send to {} added at {}",
+                    self.effective_name,
+                    return_label(send.target_index),
+                    format_db(send.amount_db)
+                )),
+                Some(old_send) if old_send.amount_db != send.amount_db => changes.push(format!(
+                    "Track {}: send to {} changed from {} to {}",
+                    self.effective_name,
+                    return_label(send.target_index),
+                    format_db(old_send.amount_db),
+                    format_db(send.amount_db)
+                )),
+                Some(_) => {}
+            }
+        }
+        for send in &old.sends {
+            if !self.sends.iter().any(|s| s.target_index == send.target_index) {
+                changes.push(format!(
+                    "Track {}: send to {} removed",
+                    self.effective_name,
+                    return_label(send.target_index)
+                ));
+            }
+        }
+
+        let old_armed_empty = old.clips.iter().filter(|c| c.clip_type == ClipType::Empty).count();
+        let new_armed_empty = self.clips.iter().filter(|c| c.clip_type == ClipType::Empty).count();
+        if new_armed_empty > old_armed_empty {
+            changes.push(format!("Track {}: slot armed but empty", self.id));
+        } else if new_armed_empty < old_armed_empty {
+            changes.push(format!("Track {}: armed-empty slot now has clip", self.id));
+        }
+
+        let old_clip_names: Vec<&str> = old.clips.iter().map(|c| c.name.as_str()).filter(|n| !n.is_empty()).collect();
+        let new_clip_names: Vec<&str> =
+            self.clips.iter().map(|c| c.name.as_str()).filter(|n| !n.is_empty()).collect();
+        for name in &old_clip_names {
+            if !new_clip_names.contains(name) {
+                changes.push(format!("Track {}: removed clip {name}", self.effective_name));
+            }
+        }
+        for name in &new_clip_names {
+            if !old_clip_names.contains(name) {
+                changes.push(format!("Track {}: added clip {name}", self.effective_name));
+            }
+        }
+
+        changes.extend(diff_clip_changes(&self.id, &old.clips, &self.clips));
+
+        for mapping in &old.mappings {
+            if !self.mappings.contains(mapping) {
+                changes.push(format!("Track {}: removed mapping '{mapping}'", self.id));
+            }
+        }
+        for mapping in &self.mappings {
+            if !old.mappings.contains(mapping) {
+                changes.push(format!("Track {}: added mapping '{mapping}'", self.id));
+            }
+        }
+
+        changes
+    }
+}
+
+/// Reports per-clip groove, sample relink, and RAM/Hi-Q changes, matching
+/// clips by name.
+fn diff_clip_changes(track_id: &str, old: &[Clip], new: &[Clip]) -> Vec<String> {
+    let mut changes = Vec::new();
+    for new_clip in new {
+        if let Some(old_clip) = old.iter().find(|c| c.name == new_clip.name) {
+            if old_clip.groove != new_clip.groove {
+                changes.push(format!(
+                    "Track {track_id}: clip '{}' groove changed from {:?} to {:?}",
+                    new_clip.name, old_clip.groove, new_clip.groove
+                ));
+            }
+            if old_clip.current_path != new_clip.current_path {
+                let same_file_name = file_name_of(&old_clip.current_path) == file_name_of(&new_clip.current_path);
+                if same_file_name {
+                    changes.push(format!(
+                        "Track {track_id}: clip '{}' sample relinked from {:?} to {:?}",
+                        new_clip.name, old_clip.current_path, new_clip.current_path
+                    ));
+                }
+            }
+            if old_clip.ram_mode != new_clip.ram_mode {
+                changes.push(format!(
+                    "Track {track_id}: clip '{}' RAM mode changed from {:?} to {:?}",
+                    new_clip.name, old_clip.ram_mode, new_clip.ram_mode
+                ));
+            }
+            if old_clip.hi_q != new_clip.hi_q {
+                changes.push(format!(
+                    "Track {track_id}: clip '{}' Hi-Q changed from {:?} to {:?}",
+                    new_clip.name, old_clip.hi_q, new_clip.hi_q
+                ));
+            }
+            if old_clip.warp != new_clip.warp {
+                changes.push(format!("Clip '{}': warping changed", new_clip.name));
+            }
+            if old_clip.color != new_clip.color {
+                changes.push(format!(
+                    "Track {track_id}: clip '{}' color changed from {:?} to {:?}",
+                    new_clip.name, old_clip.color, new_clip.color
+                ));
+            }
+            if old_clip.start_time != new_clip.start_time
+                || old_clip.loop_start != new_clip.loop_start
+                || old_clip.loop_end != new_clip.loop_end
+            {
+                changes.push(format!("Track {track_id}: clip '{}' position changed", new_clip.name));
+            }
+            changes.extend(diff_drum_hits(&new_clip.name, &old_clip.drum_hits, &new_clip.drum_hits));
+        }
+    }
+    changes
+}
+
+/// Reports per-pad hit-count deltas for a single clip's resolved drum hits.
+fn diff_drum_hits(clip_name: &str, old: &[(String, usize)], new: &[(String, usize)]) -> Vec<String> {
+    let mut changes = Vec::new();
+    for (pad, new_count) in new {
+        let old_count = old.iter().find(|(p, _)| p == pad).map(|(_, c)| *c).unwrap_or(0);
+        if *new_count > old_count {
+            changes.push(format!("Clip '{clip_name}': added {} {pad} hits", new_count - old_count));
+        }
+    }
+    for (pad, old_count) in old {
+        let new_count = new.iter().find(|(p, _)| p == pad).map(|(_, c)| *c).unwrap_or(0);
+        if new_count < *old_count {
+            changes.push(format!("Clip '{clip_name}': removed {} {pad} hits", old_count - new_count));
+        }
+    }
+    changes
+}
+
+fn file_name_of(path: &Option<String>) -> Option<&str> {
+    path.as_deref().and_then(|p| p.rsplit(['/', '\\']).next())
+}
+
+/// Labels a return-track index the way Live's UI does ("Return A", "Return
+/// B", ...). Falls back to the bare index past `Z` rather than producing a
+/// double letter, since a project with 26+ returns is vanishingly rare.
+fn return_label(index: i32) -> String {
+    match u8::try_from(index) {
+        Ok(index @ 0..=25) => format!("Return {}", (b'A' + index) as char),
+        _ => format!("Return {index}"),
+    }
+}
+
+/// Formats a send amount for a diff line: `-inf` for no/silent send, a
+/// rounded `NdB` otherwise.
+fn format_db(amount_db: Option<f64>) -> String {
+    match amount_db {
+        None => "-inf".to_string(),
+        Some(db) if db == f64::NEG_INFINITY => "-inf".to_string(),
+        Some(db) => format!("{}dB", db.round() as i64),
+    }
+}
+
+/// Formats a routing target for a diff line: `'Master'`/`'Group Drums'` for
+/// a known target, `none` when routing wasn't present in the file.
+fn format_routing(routing: &Option<String>) -> String {
+    match routing {
+        Some(target) => format!("'{target}'"),
+        None => "none".to_string(),
+    }
+}
+
+/// Formats a crossfade assignment for a diff line, unwrapping the `Option`
+/// so the prose reads "from None to A" rather than "from None to Some(A)".
+fn format_crossfade(assign: Option<CrossfadeAssign>) -> &'static str {
+    match assign {
+        None => "None",
+        Some(CrossfadeAssign::A) => "A",
+        Some(CrossfadeAssign::B) => "B",
+    }
+}
+
+/// Compares two branch lists belonging to the same track, matching branches
+/// within each `branch_type` group by `effective_name` where that name is
+/// unique on both sides, and falling back to positional matching (within
+/// the remainder of the group) when names collide or are absent. Reports
+/// `Branch added`/`Branch removed`/`Branch X renamed to Y`, enabled/bypassed,
+/// macro/preset changes, and compressor/delay/saturator parameter changes on
+/// each matched pair, then recurses into nested `branches`, up to
+/// `opts.max_branch_depth` levels deep (the same cap applies to every check
+/// above, since they all run from within this one matched-pair traversal).
+fn diff_branch_lists(track_id: &str, old: &[Branch], new: &[Branch], opts: &DiffOptions) -> Vec<String> {
+    diff_branch_lists_at_depth(track_id, old, new, opts, 0)
+}
+
+fn diff_branch_lists_at_depth(
+    track_id: &str,
+    old: &[Branch],
+    new: &[Branch],
+    opts: &DiffOptions,
+    depth: usize,
+) -> Vec<String> {
+    let mut changes = Vec::new();
+
+    if depth >= opts.max_branch_depth {
+        for branch in old.iter().chain(new.iter()) {
+            changes.push(format!("Branch {}: nesting too deep to diff", branch.effective_name));
+        }
+        return changes;
+    }
+
+    let mut branch_types: Vec<&str> = old
+        .iter()
+        .chain(new.iter())
+        .map(|b| b.branch_type.as_str())
+        .collect();
+    branch_types.sort_unstable();
+    branch_types.dedup();
+
+    for branch_type in branch_types {
+        let old_idxs: Vec<usize> =
+            old.iter().enumerate().filter(|(_, b)| b.branch_type == branch_type).map(|(i, _)| i).collect();
+        let new_idxs: Vec<usize> =
+            new.iter().enumerate().filter(|(_, b)| b.branch_type == branch_type).map(|(i, _)| i).collect();
+
+        let mut used_old = vec![false; old_idxs.len()];
+        let mut used_new = vec![false; new_idxs.len()];
+        let mut pairs: Vec<(usize, usize)> = Vec::new();
+
+        for (oi_pos, &oi) in old_idxs.iter().enumerate() {
+            let name = &old[oi].effective_name;
+            let unique_in_old = old_idxs.iter().filter(|&&i| old[i].effective_name == *name).count() == 1;
+            let unique_in_new = new_idxs.iter().filter(|&&i| new[i].effective_name == *name).count() == 1;
+            if !unique_in_old || !unique_in_new {
+                continue;
+            }
+            if let Some(ni_pos) = new_idxs.iter().position(|&ni| new[ni].effective_name == *name) {
+                pairs.push((oi, new_idxs[ni_pos]));
+                used_old[oi_pos] = true;
+                used_new[ni_pos] = true;
+            }
+        }
+
+        let remaining_old: Vec<usize> =
+            old_idxs.iter().enumerate().filter(|(p, _)| !used_old[*p]).map(|(_, &i)| i).collect();
+        let remaining_new: Vec<usize> =
+            new_idxs.iter().enumerate().filter(|(p, _)| !used_new[*p]).map(|(_, &i)| i).collect();
+
+        let matched = remaining_old.len().min(remaining_new.len());
+        for i in 0..matched {
+            pairs.push((remaining_old[i], remaining_new[i]));
+        }
+        for &oi in &remaining_old[matched..] {
+            changes.push(format!("Track {track_id}: Branch removed ({})", old[oi].effective_name));
+        }
+        for &ni in &remaining_new[matched..] {
+            changes.push(format!("Track {track_id}: Branch added ({})", new[ni].effective_name));
+        }
+
+        for (oi, ni) in pairs {
+            let old_branch = &old[oi];
+            let new_branch = &new[ni];
+            if old_branch.effective_name != new_branch.effective_name {
+                changes.push(format!(
+                    "Track {track_id}: Branch {} renamed to {}",
+                    old_branch.effective_name, new_branch.effective_name
+                ));
+            }
+            let was_enabled = old_branch.enabled.unwrap_or(true);
+            let is_enabled = new_branch.enabled.unwrap_or(true);
+            if was_enabled != is_enabled {
+                changes.push(format!(
+                    "Branch {}: {}",
+                    new_branch.effective_name,
+                    if is_enabled { "re-enabled" } else { "bypassed" }
+                ));
+            }
+            for new_macro in &new_branch.macros {
+                if let Some(old_macro) = old_branch.macros.iter().find(|m| m.name == new_macro.name) {
+                    if old_macro.value != new_macro.value {
+                        changes.push(format!(
+                            "Branch {}: macro '{}' changed from {} to {}",
+                            new_branch.effective_name, new_macro.name, old_macro.value, new_macro.value
+                        ));
+                    }
+                }
+            }
+            if let (Some(old_hash), Some(new_hash)) = (old_branch.state_hash, new_branch.state_hash) {
+                if old_hash != new_hash {
+                    changes.push(format!("Branch {}: preset changed", new_branch.effective_name));
+                }
+            }
+            if let (Some(o), Some(n)) = (old_branch.compressor, new_branch.compressor) {
+                if o.threshold != n.threshold {
+                    changes.push(format!(
+                        "Branch {}: compressor threshold changed from {} to {}",
+                        new_branch.effective_name, o.threshold, n.threshold
+                    ));
+                }
+                if o.ratio != n.ratio {
+                    changes.push(format!(
+                        "Branch {}: compressor ratio changed from {} to {}",
+                        new_branch.effective_name, o.ratio, n.ratio
+                    ));
+                }
+            }
+            if let (Some(o), Some(n)) = (old_branch.delay, new_branch.delay) {
+                if o.sync != n.sync {
+                    changes.push(format!(
+                        "Branch {}: delay sync changed from {} to {}",
+                        new_branch.effective_name, o.sync, n.sync
+                    ));
+                }
+                if o.delay_time != n.delay_time {
+                    changes.push(format!(
+                        "Branch {}: delay time changed from {} to {}",
+                        new_branch.effective_name, o.delay_time, n.delay_time
+                    ));
+                }
+                if o.feedback != n.feedback {
+                    changes.push(format!(
+                        "Branch {}: delay feedback changed from {} to {}",
+                        new_branch.effective_name, o.feedback, n.feedback
+                    ));
+                }
+            }
+            if let (Some(o), Some(n)) = (old_branch.saturator, new_branch.saturator) {
+                if o.drive != n.drive {
+                    changes.push(format!(
+                        "Branch {}: saturator drive changed from {} to {}",
+                        new_branch.effective_name, o.drive, n.drive
+                    ));
+                }
+                if o.output != n.output {
+                    changes.push(format!(
+                        "Branch {}: saturator output changed from {} to {}",
+                        new_branch.effective_name, o.output, n.output
+                    ));
+                }
+            }
+            changes.extend(diff_branch_lists_at_depth(
+                track_id,
+                &old_branch.branches,
+                &new_branch.branches,
+                opts,
+                depth + 1,
+            ));
+        }
+    }
+
+    changes
+}
+
+/// Clears fields that represent layout/cosmetic state rather than semantic
+/// content, so equality comparisons can ignore them. Recursion is capped at
+/// [`DiffOptions::default`]'s `max_branch_depth`, the same limit
+/// [`diff_branch_lists_at_depth`] uses, so a pathologically deep rack can't
+/// overflow the stack here either; branches past the cap are truncated away
+/// rather than compared.
+fn strip_cosmetic(branches: &[Branch]) -> Vec<Branch> {
+    strip_cosmetic_at_depth(branches, 0)
+}
+
+fn strip_cosmetic_at_depth(branches: &[Branch], depth: usize) -> Vec<Branch> {
+    if depth >= DiffOptions::default().max_branch_depth {
+        return Vec::new();
+    }
+    branches
+        .iter()
+        .map(|b| {
+            let mut stripped = b.clone();
+            stripped.expanded = None;
+            stripped.branches = strip_cosmetic_at_depth(&b.branches, depth + 1);
+            stripped
+        })
+        .collect()
+}
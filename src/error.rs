@@ -0,0 +1,51 @@
+//! Error type shared by the parsing entry points.
+
+use std::fmt;
+
+#[derive(Debug)]
+pub enum ParseError {
+    Io(std::io::Error),
+    /// The input wasn't a valid gzip stream (e.g. bad magic bytes or a
+    /// truncated header), distinct from an XML parse failure further in.
+    Gzip(String),
+    Xml(quick_xml::Error),
+    /// A branch (rack chain) was left open with nowhere to attach it to at
+    /// end of file — more `<AudioEffectBranch>`-style opens than closes, and
+    /// no enclosing track to fold the leftovers into.
+    UnbalancedXml(String),
+    /// A cached `Project` snapshot (e.g. from [`crate::Project::from_json_path`])
+    /// wasn't valid JSON, or didn't match the `Project` shape.
+    Json(serde_json::Error),
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseError::Io(e) => write!(f, "io error: {e}"),
+            ParseError::Gzip(msg) => write!(f, "gzip error: {msg}"),
+            ParseError::Xml(e) => write!(f, "xml error: {e}"),
+            ParseError::UnbalancedXml(msg) => write!(f, "unbalanced xml: {msg}"),
+            ParseError::Json(e) => write!(f, "json error: {e}"),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+impl From<std::io::Error> for ParseError {
+    fn from(e: std::io::Error) -> Self {
+        ParseError::Io(e)
+    }
+}
+
+impl From<quick_xml::Error> for ParseError {
+    fn from(e: quick_xml::Error) -> Self {
+        ParseError::Xml(e)
+    }
+}
+
+impl From<serde_json::Error> for ParseError {
+    fn from(e: serde_json::Error) -> Self {
+        ParseError::Json(e)
+    }
+}
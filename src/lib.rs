@@ -0,0 +1,192 @@
+//! A fast parser for Ableton Live Set (`.als`) files, exposed both as a Rust
+//! library and as a Node addon via `napi`.
+
+pub mod batch;
+pub mod cache;
+pub mod change;
+pub mod diff;
+pub mod error;
+pub mod model;
+pub mod parse;
+pub mod reader;
+
+pub use batch::{parse_directory, parse_directory_recursive};
+pub use cache::ParserCache;
+pub use change::{ApplyError, Change};
+pub use diff::{DiffCounts, DiffOptions, DiffStats};
+pub use error::ParseError;
+pub use model::{
+    AbletonMeta, Branch, Clip, ClipType, ClipView, CompressorParams, CrossfadeAssign, DelayParams,
+    IntegrityReport, Locator, Macro, Note, ParseMetrics, Project, SaturatorParams, Scene, Track, TrackNode,
+    TrackSend, TrackType, WarpInfo, WarpMarker, LIVE_COLOR_PALETTE,
+};
+pub use parse::{
+    get_ableton_meta, get_project_from_als, parse_metadata_only, parse_project_from_bytes,
+    parse_project_from_reader, parse_project_with_config, parse_project_with_metrics, parse_project_with_options,
+    parse_with_handler, visit_tracks, EventHandler, ParseConfig, ParseOptions,
+};
+pub use reader::{decompress_als, decompress_als_bytes};
+
+use std::path::Path;
+use std::sync::{Mutex, OnceLock};
+
+use napi_derive::napi;
+
+/// Parses `current_filepath`, diffs it against the `Project` cached in
+/// `old_json_path`, and returns a JSON string with `"summary"`, `"changes"`,
+/// `"stats"`, and `"project"` keys. `"changes"` is the structured [`Change`]
+/// list from [`Project::diff_structured`], for callers that want to render
+/// or apply the diff without string-matching `"summary"`'s prose. `"stats"`
+/// is the [`DiffStats`] headline counters for a dashboard badge.
+///
+/// When `light` is `true`, the `"project"` key is set to `null` in the
+/// response so large sets don't blow past the Electron IPC channel's
+/// size limit. Callers that go `light` are expected to fetch per-track detail
+/// lazily instead. Defaults to `false` (full payload) for callers that don't
+/// pass it, matching the pre-`light` behavior.
+#[napi]
+pub fn parse_xml(current_filepath: String, old_json_path: String, light: Option<bool>) -> napi::Result<String> {
+    let light = light.unwrap_or(false);
+    let project = get_project_from_als(&current_filepath)
+        .map_err(|e| napi::Error::from_reason(format!("failed to parse {current_filepath}: {e}")))?;
+
+    let old_project = Project::from_json_path(&old_json_path)
+        .map_err(|e| napi::Error::from_reason(format!("failed to parse {old_json_path}: {e}")))?;
+
+    build_diff_response(&project, &old_project, light)
+}
+
+/// Async counterpart to [`parse_xml`], for callers that can't afford to
+/// block the Node event loop while a large set parses and diffs. Runs the
+/// same work on a worker thread and resolves with an identical JSON string;
+/// parse/read errors surface as a rejected promise with the same messages.
+#[napi]
+pub async fn parse_xml_async(current_filepath: String, old_json_path: String, light: Option<bool>) -> napi::Result<String> {
+    napi::tokio::task::spawn_blocking(move || parse_xml(current_filepath, old_json_path, light))
+        .await
+        .map_err(|e| napi::Error::from_reason(format!("parse task panicked: {e}")))?
+}
+
+/// The shared cache behind [`parse_xml_cached`], holding the most recently
+/// parsed [`Project`] per path so an Electron frontend re-running a diff
+/// after every save doesn't re-decompress and re-parse an unchanged file.
+fn parser_cache() -> &'static Mutex<ParserCache> {
+    static CACHE: OnceLock<Mutex<ParserCache>> = OnceLock::new();
+    CACHE.get_or_init(|| Mutex::new(ParserCache::new()))
+}
+
+/// Like [`parse_xml`], but parses `current_filepath` through the shared
+/// [`ParserCache`] instead of always re-reading it from disk.
+#[napi]
+pub fn parse_xml_cached(current_filepath: String, old_json_path: String, light: Option<bool>) -> napi::Result<String> {
+    let light = light.unwrap_or(false);
+    let project = parser_cache()
+        .lock()
+        .expect("parser cache mutex poisoned")
+        .get_or_parse(&current_filepath)
+        .map_err(|e| napi::Error::from_reason(format!("failed to parse {current_filepath}: {e}")))?;
+
+    let old_project = Project::from_json_path(&old_json_path)
+        .map_err(|e| napi::Error::from_reason(format!("failed to parse {old_json_path}: {e}")))?;
+
+    build_diff_response(&project, &old_project, light)
+}
+
+/// Parses `current_filepath` and `old_filepath` directly and diffs them,
+/// for callers comparing two `.als` files (e.g. a backup and the current
+/// save) that don't have a cached JSON snapshot of the old side.
+///
+/// Returns the same response shape as [`parse_xml`]: `"summary"`,
+/// `"changes"`, `"stats"`, and `"project"` keys.
+#[napi]
+pub fn diff_als(current_filepath: String, old_filepath: String) -> napi::Result<String> {
+    let project = get_project_from_als(&current_filepath)
+        .map_err(|e| napi::Error::from_reason(format!("failed to parse {current_filepath}: {e}")))?;
+    let old_project = get_project_from_als(&old_filepath)
+        .map_err(|e| napi::Error::from_reason(format!("failed to parse {old_filepath}: {e}")))?;
+
+    build_diff_response(&project, &old_project, false)
+}
+
+/// Parses `filepath` and returns every external sample file it depends on,
+/// deduplicated, for asset-collection tooling that needs to gather a
+/// project's dependencies without caring about track/clip structure.
+#[napi]
+pub fn list_samples(filepath: String) -> napi::Result<Vec<String>> {
+    let project = get_project_from_als(&filepath)
+        .map_err(|e| napi::Error::from_reason(format!("failed to parse {filepath}: {e}")))?;
+    Ok(project.samples)
+}
+
+/// Returns `filepath`'s arrangement length in beats, for a library browser
+/// showing each project's duration. Session-only projects with no
+/// arrangement clips have no defined length, so they return `0.0` rather
+/// than an error.
+#[napi]
+pub fn project_length(filepath: String) -> napi::Result<f64> {
+    let project = get_project_from_als(&filepath)
+        .map_err(|e| napi::Error::from_reason(format!("failed to parse {filepath}: {e}")))?;
+    Ok(project.length_beats.unwrap_or(0.0))
+}
+
+/// Returns the `Creator` string (e.g. `"Ableton Live 11.3.4"`) that saved
+/// `filepath`, so a frontend can warn when a file was saved by a newer Live
+/// version than the user has installed. Reads only the root element.
+#[napi]
+pub fn detect_version(filepath: String) -> napi::Result<String> {
+    let meta = get_ableton_meta(&filepath)
+        .map_err(|e| napi::Error::from_reason(format!("failed to parse {filepath}: {e}")))?;
+    Ok(meta.creator.unwrap_or_default())
+}
+
+/// Parses every `.als` file in `dir` (recursing when `recursive` is `true`)
+/// in parallel, for library browsers indexing large collections of sets.
+/// Returns a JSON array of `{ path, trackCount, error }` objects; a file
+/// that failed to parse gets a `null` `trackCount` and a non-null `error`
+/// instead of aborting the whole batch.
+#[napi]
+pub fn parse_als_directory(dir: String, recursive: Option<bool>) -> napi::Result<String> {
+    let recursive = recursive.unwrap_or(false);
+    let results = if recursive {
+        parse_directory_recursive(Path::new(&dir))
+    } else {
+        parse_directory(Path::new(&dir))
+    };
+
+    let entries: Vec<serde_json::Value> = results
+        .into_iter()
+        .map(|(path, result)| match result {
+            Ok(project) => serde_json::json!({
+                "path": path.to_string_lossy(),
+                "trackCount": project.tracks.len(),
+                "error": null,
+            }),
+            Err(e) => serde_json::json!({
+                "path": path.to_string_lossy(),
+                "trackCount": null,
+                "error": e.to_string(),
+            }),
+        })
+        .collect();
+
+    serde_json::to_string(&entries)
+        .map_err(|e| napi::Error::from_reason(format!("failed to serialize response: {e}")))
+}
+
+/// Builds the shared `"summary"`/`"changes"`/`"project"` JSON response used
+/// by [`parse_xml`] and [`diff_als`].
+fn build_diff_response(project: &Project, old_project: &Project, light: bool) -> napi::Result<String> {
+    let changes = project.diff(old_project);
+    let structured_changes = project.diff_structured(old_project);
+    let stats = project.diff_stats(old_project);
+
+    let response = serde_json::json!({
+        "summary": changes.join("\n"),
+        "changes": structured_changes,
+        "stats": stats,
+        "project": if light { serde_json::Value::Null } else { serde_json::to_value(project).unwrap_or(serde_json::Value::Null) },
+    });
+
+    serde_json::to_string(&response)
+        .map_err(|e| napi::Error::from_reason(format!("failed to serialize response: {e}")))
+}
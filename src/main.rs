@@ -0,0 +1,43 @@
+//! Standalone CLI for diffing two Ableton `.als` files.
+
+use als_parser_rs::get_project_from_als;
+
+fn main() {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let json_mode = args.iter().any(|a| a == "--json");
+    let paths: Vec<&String> = args.iter().filter(|a| a.as_str() != "--json").collect();
+
+    if paths.len() != 2 {
+        eprintln!("Usage: als_parser_rs [--json] <old.als> <new.als>");
+        std::process::exit(1);
+    }
+    let old_path = paths[0];
+    let new_path = paths[1];
+
+    let old_project = get_project_from_als(old_path).unwrap_or_else(|e| {
+        eprintln!("failed to parse {old_path}: {e}");
+        std::process::exit(1);
+    });
+    let new_project = get_project_from_als(new_path).unwrap_or_else(|e| {
+        eprintln!("failed to parse {new_path}: {e}");
+        std::process::exit(1);
+    });
+
+    if json_mode {
+        let payload = serde_json::json!({
+            "project": new_project,
+            "changes": new_project.diff_structured(&old_project),
+        });
+        println!("{}", serde_json::to_string_pretty(&payload).unwrap_or_default());
+        return;
+    }
+
+    let changes = new_project.diff(&old_project);
+    if changes.is_empty() {
+        println!("No changes.");
+    } else {
+        for change in changes {
+            println!("{change}");
+        }
+    }
+}
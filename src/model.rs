@@ -0,0 +1,816 @@
+//! Data model for a parsed Ableton Live Set.
+
+use std::fs::File;
+
+use serde::{Deserialize, Serialize};
+
+use crate::error::ParseError;
+
+/// Just the root `<Ableton>` element's attributes, for callers that only
+/// need to version-audit a folder of sets without parsing tracks.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct AbletonMeta {
+    /// The `Version` attribute on the root element (the Live Set schema version).
+    pub version: Option<String>,
+    /// The `Creator` attribute on the root element (e.g. `"Ableton Live 11.0.1"`).
+    pub creator: Option<String>,
+}
+
+/// A device or rack chain found inside a track's device chain.
+///
+/// Racks (Instrument Rack, Audio Effect Rack, Drum Rack) hold one or more
+/// `Branch`es, each of which can itself contain nested racks.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Branch {
+    pub branch_type: String,
+    #[serde(default)]
+    pub user_name: Option<String>,
+    pub effective_name: String,
+    #[serde(default)]
+    pub branches: Vec<Branch>,
+    /// Whether this chain is expanded (vs. collapsed) in the device view.
+    /// Cosmetic layout state; excluded from semantic diffs by default.
+    #[serde(default)]
+    pub expanded: Option<bool>,
+    /// Targeted `<Compressor2>` parameters, when this branch's chain contains one.
+    #[serde(default)]
+    pub compressor: Option<CompressorParams>,
+    /// For a `DrumBranch` pad, the MIDI note number that triggers it, from
+    /// `<ReceivingNote>`. Used to resolve a clip's raw note pitches to pad
+    /// names for `Clip::drum_hits`.
+    #[serde(default)]
+    pub receiving_note: Option<i32>,
+    /// Targeted `<Delay>` parameters, when this branch's chain contains one.
+    #[serde(default)]
+    pub delay: Option<DelayParams>,
+    /// Targeted `<Saturator>` parameters, when this branch's chain contains one.
+    #[serde(default)]
+    pub saturator: Option<SaturatorParams>,
+    /// On/bypass state of this chain's device, from its `<On>`/`Manual`
+    /// value. `None` means the `On` element was absent, which Live treats as
+    /// enabled, not bypassed.
+    #[serde(default)]
+    pub enabled: Option<bool>,
+    /// This rack's macro knobs, from `MacroControls.N`/`MacroDisplayNames.N`.
+    /// Slots left at their default name and zero value are dropped; see
+    /// `is_untouched_macro` in `parse.rs`.
+    #[serde(default)]
+    pub macros: Vec<Macro>,
+    /// A fast, non-cryptographic hash of this chain's plugin preset
+    /// (`Data`/`Buffer` blob), so a diff can notice an internal preset
+    /// tweak that left the plugin's name unchanged. `None` when the chain
+    /// has no plugin or the plugin stored no data blob.
+    #[serde(default)]
+    pub state_hash: Option<u64>,
+}
+
+/// One macro knob on a rack, from `MacroControls.N`/`MacroDisplayNames.N`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Macro {
+    pub name: String,
+    pub value: f64,
+}
+
+/// Drive/output for a `<Saturator>` device, captured so mix-review diffs can
+/// call out coloration tweaks by name instead of a generic "rack modified"
+/// message.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct SaturatorParams {
+    pub drive: f64,
+    pub output: f64,
+}
+
+/// Sync/time/feedback for a `<Delay>` device, captured so mix-review diffs
+/// can report them by name instead of a generic "rack modified" message.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct DelayParams {
+    /// Whether the delay time is synced to the project tempo (vs. free ms).
+    pub sync: bool,
+    pub delay_time: f64,
+    pub feedback: f64,
+}
+
+/// Threshold/ratio/attack/release for a `<Compressor2>` device, captured so
+/// mix-review diffs can report them by name instead of a generic
+/// "rack modified" message.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct CompressorParams {
+    pub threshold: f64,
+    pub ratio: f64,
+    pub attack: f64,
+    pub release: f64,
+}
+
+/// One entry in a track's `<Sends>` list: a target return track and how much
+/// signal is routed to it, from `<Send Id="..">`'s `Manual` value.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct TrackSend {
+    /// Index of the target return track, matching its position among
+    /// `TrackType::Return` tracks. Validated against the actual return count
+    /// by [`Project::dangling_sends`].
+    pub target_index: i32,
+    /// The send amount in dB, converted from the raw linear fader value.
+    /// `None` if the `Send` element had no nested `Manual` value (an empty
+    /// send holder, not an active send).
+    #[serde(default)]
+    pub amount_db: Option<f64>,
+}
+
+/// Converts a linear gain value (as stored in `.als` XML) to dB.
+/// `0.0` (or negative, which shouldn't occur but is handled defensively)
+/// maps to negative infinity rather than panicking on `log10`.
+pub(crate) fn linear_to_db(linear: f64) -> f64 {
+    if linear <= 0.0 {
+        f64::NEG_INFINITY
+    } else {
+        20.0 * linear.log10()
+    }
+}
+
+impl Branch {
+    pub fn new(branch_type: impl Into<String>) -> Self {
+        Branch {
+            branch_type: branch_type.into(),
+            user_name: None,
+            effective_name: String::new(),
+            branches: Vec::new(),
+            expanded: None,
+            compressor: None,
+            receiving_note: None,
+            delay: None,
+            saturator: None,
+            enabled: None,
+            macros: Vec::new(),
+            state_hash: None,
+        }
+    }
+
+    pub fn set_user_name(&mut self, value: &str) {
+        self.user_name = Some(value.to_string());
+    }
+
+    pub fn set_effective_name(&mut self, value: &str) {
+        self.effective_name = value.to_string();
+    }
+
+    /// Returns a mutable reference to the macro slot at `index`, extending
+    /// `macros` with default-named zero-value slots as needed.
+    pub(crate) fn macro_slot_mut(&mut self, index: usize) -> &mut Macro {
+        while self.macros.len() <= index {
+            let i = self.macros.len();
+            self.macros.push(Macro {
+                name: format!("Macro {i}"),
+                value: 0.0,
+            });
+        }
+        &mut self.macros[index]
+    }
+}
+
+/// Whether a clip lives in the Session view's clip slots or the Arrangement timeline.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ClipView {
+    Session,
+    Arrangement,
+}
+
+/// The kind of clip, parsed from its XML element name.
+///
+/// Serializes as the familiar element-name strings so existing JSON
+/// consumers that string-match `"MidiClip"`/`"AudioClip"` keep working.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ClipType {
+    #[serde(rename = "MidiClip")]
+    Midi,
+    #[serde(rename = "AudioClip")]
+    Audio,
+    /// A recording-armed clip slot with no clip in it yet. Distinguished
+    /// from an ordinary empty slot so a diff can report "slot armed but
+    /// empty" instead of silently having nothing to compare.
+    #[serde(rename = "Empty")]
+    Empty,
+}
+
+impl ClipType {
+    /// Maps an XML element name (e.g. `"MidiClip"`) to a `ClipType`.
+    pub fn from_element_name(name: &str) -> Option<ClipType> {
+        match name {
+            "MidiClip" => Some(ClipType::Midi),
+            "AudioClip" => Some(ClipType::Audio),
+            _ => None,
+        }
+    }
+}
+
+/// A single clip (MIDI or audio) found in a track's clip slots.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Clip {
+    pub name: String,
+    pub clip_type: ClipType,
+    pub view: ClipView,
+    /// The resolved name of the groove-pool entry assigned to this clip, if any.
+    #[serde(default)]
+    pub groove: Option<String>,
+    /// Where the referenced sample originally lived, from `FileRef`. Audio clips only.
+    #[serde(default)]
+    pub original_path: Option<String>,
+    /// Where the referenced sample currently resolves to, from `FileRef`. Audio clips only.
+    #[serde(default)]
+    pub current_path: Option<String>,
+    /// RAM-mode playback flag, from `<Ram>`. Audio clips only.
+    #[serde(default)]
+    pub ram_mode: Option<bool>,
+    /// Hi-Q interpolation flag, from `<HiQ>`. Audio clips only.
+    #[serde(default)]
+    pub hi_q: Option<bool>,
+    /// Drum-pad names hit by this clip's notes, paired with hit counts, when
+    /// the owning track has a drum rack. Resolved from raw note pitches via
+    /// the track's `DrumBranch` `receiving_note` mappings once the track
+    /// finishes parsing. Empty for clips on non-drum tracks.
+    #[serde(default)]
+    pub drum_hits: Vec<(String, usize)>,
+    /// Raw MIDI note pitches recorded while parsing, before they're resolved
+    /// to drum-pad names. Not part of the public JSON shape.
+    #[serde(skip)]
+    pub(crate) note_pitches: Vec<i32>,
+    /// Warp/timestretch state, from `IsWarped`/`WarpMode`/`WarpMarkers`.
+    /// Audio clips only; always `Some` for them, even when unwarped, so
+    /// callers can match on `warp.is_warped` without an extra `None` case.
+    /// `None` for MIDI and empty clips.
+    #[serde(default)]
+    pub warp: Option<WarpInfo>,
+    /// This clip's MIDI notes, from `Notes`/`KeyTracks`/`KeyTrack`'s
+    /// `MidiKey` paired with each of its `Notes`/`MidiNoteEvent` entries.
+    /// Empty for audio clips and clips with no notes.
+    #[serde(default)]
+    pub notes: Vec<Note>,
+    /// The source sample's sample rate, from `SampleRef`/`FileRef`'s
+    /// `DefaultSampleRate`. Audio clips only.
+    #[serde(default)]
+    pub sample_rate: Option<u32>,
+    /// Coarse pitch shift in semitones, from `PitchCoarse`/`Value`. Audio clips only.
+    #[serde(default)]
+    pub pitch_coarse: Option<i32>,
+    /// Fine pitch shift in cents, from `PitchFine`/`Value`. Audio clips only.
+    #[serde(default)]
+    pub pitch_fine: Option<i32>,
+    /// This clip's position on the Arrangement timeline in beats, from its
+    /// own `Time` attribute. `None` for Session clips, which have no fixed
+    /// timeline position.
+    #[serde(default)]
+    pub start_time: Option<f64>,
+    /// The start of the clip's active loop/sample window in beats, from
+    /// `CurrentStart`/`Value`.
+    #[serde(default)]
+    pub loop_start: Option<f64>,
+    /// The end of the clip's active loop/sample window in beats, from
+    /// `CurrentEnd`/`Value`.
+    #[serde(default)]
+    pub loop_end: Option<f64>,
+    /// Index into Live's fixed color palette ([`LIVE_COLOR_PALETTE`]), from
+    /// the clip's `Color` element. `None` when the clip uses the track's
+    /// color instead of its own.
+    #[serde(default)]
+    pub color: Option<u8>,
+    /// The referenced sample's size in bytes at the time it was last
+    /// analyzed, from `FileRef`'s `OriginalFileSize`. Audio clips only.
+    #[serde(default)]
+    pub original_file_size: Option<u64>,
+    /// The referenced sample's CRC checksum at the time it was last
+    /// analyzed, from `FileRef`'s `OriginalCrc`. Used by Live to detect a
+    /// sample that changed on disk without being renamed. Audio clips only.
+    #[serde(default)]
+    pub original_crc: Option<u32>,
+}
+
+impl Clip {
+    pub fn new(clip_type: ClipType, view: ClipView) -> Self {
+        Clip {
+            name: String::new(),
+            clip_type,
+            view,
+            groove: None,
+            original_path: None,
+            current_path: None,
+            ram_mode: None,
+            hi_q: None,
+            drum_hits: Vec::new(),
+            note_pitches: Vec::new(),
+            warp: (clip_type == ClipType::Audio).then(WarpInfo::default),
+            notes: Vec::new(),
+            sample_rate: None,
+            pitch_coarse: None,
+            pitch_fine: None,
+            start_time: None,
+            loop_start: None,
+            loop_end: None,
+            color: None,
+            original_file_size: None,
+            original_crc: None,
+        }
+    }
+}
+
+/// One MIDI note, from a `KeyTrack`'s `MidiKey` paired with one of its
+/// `MidiNoteEvent` entries.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Note {
+    pub pitch: u8,
+    pub time: f64,
+    pub duration: f64,
+    pub velocity: u8,
+    /// Whether the note is muted, from `MidiNoteEvent`'s `IsEnabled="false"`.
+    /// `false` (not muted) when the attribute is absent, matching Live's own
+    /// default of an enabled note.
+    #[serde(default)]
+    pub mute: bool,
+}
+
+/// An audio clip's warp/timestretch state, from `IsWarped`, `WarpMode`, and
+/// the `WarpMarkers` list.
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct WarpInfo {
+    pub is_warped: bool,
+    pub warp_mode: Option<i32>,
+    pub markers: Vec<WarpMarker>,
+}
+
+/// One entry in a `WarpMarkers` list, pairing a position in the source
+/// audio (`sec_time`) with where it's mapped to on the timeline (`beat_time`).
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct WarpMarker {
+    pub sec_time: f64,
+    pub beat_time: f64,
+}
+
+/// The kind of track, parsed from its XML element name.
+///
+/// Serializes as the familiar element-name strings for JSON compatibility.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TrackType {
+    #[serde(rename = "AudioTrack")]
+    Audio,
+    #[serde(rename = "MidiTrack")]
+    Midi,
+    #[serde(rename = "ReturnTrack")]
+    Return,
+    #[serde(rename = "GroupTrack")]
+    Group,
+    #[serde(rename = "MasterTrack")]
+    Master,
+}
+
+impl TrackType {
+    /// Maps an XML element name (e.g. `"AudioTrack"`) to a `TrackType`.
+    pub fn from_element_name(name: &str) -> Option<TrackType> {
+        match name {
+            "AudioTrack" => Some(TrackType::Audio),
+            "MidiTrack" => Some(TrackType::Midi),
+            "ReturnTrack" => Some(TrackType::Return),
+            "GroupTrack" => Some(TrackType::Group),
+            "MasterTrack" => Some(TrackType::Master),
+            _ => None,
+        }
+    }
+}
+
+/// Crossfader assignment (the A/B switch on a track's mixer strip).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum CrossfadeAssign {
+    A,
+    B,
+}
+
+/// A single track in the Live Set.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Track {
+    pub id: String,
+    pub track_type: TrackType,
+    #[serde(default)]
+    pub user_name: Option<String>,
+    pub effective_name: String,
+    #[serde(default)]
+    pub branches: Vec<Branch>,
+    /// Names of the take-lane segments composing the currently active comp,
+    /// in comp order. Empty when the track has no comping data (older Live
+    /// versions, or tracks that were never comped).
+    #[serde(default)]
+    pub comp_sources: Vec<String>,
+    /// `Some(true)` when the track's output routing is set to "Sends Only"
+    /// (no main-bus signal). `None` when routing wasn't present in the file.
+    #[serde(default)]
+    pub sends_only: Option<bool>,
+    /// The `Id` of the group track this track is nested under, if any.
+    /// `None` (or `"-1"` in the XML) means top-level.
+    #[serde(default)]
+    pub group_id: Option<String>,
+    #[serde(default)]
+    pub clips: Vec<Clip>,
+    /// This track's sends, from `<Sends>`. A target index with no
+    /// corresponding return track (e.g. the return was deleted but the send
+    /// holder lingers) is a dangling send; see [`Project::dangling_sends`].
+    #[serde(default)]
+    pub sends: Vec<TrackSend>,
+    /// Plugin delay compensation enable state, from `<DelayCompensation>`.
+    /// `None` when the element is absent from the file.
+    #[serde(default)]
+    pub pdc_enabled: Option<bool>,
+    /// Names of the key/MIDI mapping targets bound on this track, from
+    /// `<KeyMidiMappings>`. Performance templates version these controller
+    /// bindings, so drift here matters even though the mapped value itself
+    /// isn't captured.
+    #[serde(default)]
+    pub mappings: Vec<String>,
+    /// Mute state, from the track's `Speaker` element. `None` when the
+    /// element is absent from the file.
+    #[serde(default)]
+    pub muted: Option<bool>,
+    /// Solo state, from the track's `Solo` element. `None` when the element
+    /// is absent from the file.
+    #[serde(default)]
+    pub soloed: Option<bool>,
+    /// Arm (record-enable) state, from the track's `Arm` element. `None`
+    /// when the element is absent from the file.
+    #[serde(default)]
+    pub armed: Option<bool>,
+    /// Index into Live's fixed color palette, from the track's `Color`
+    /// element. `None` when the element is absent from the file.
+    #[serde(default)]
+    pub color: Option<u8>,
+    /// Device identifiers from the track's top-level `DeviceChain` (outside
+    /// any rack branch), in chain order. Plugins are identified by their
+    /// `PlugName`/`FileName`; native devices by their element tag.
+    #[serde(default)]
+    pub devices: Vec<String>,
+    /// Linear fader gain, from the track's `Mixer`/`Volume`/`Manual` value.
+    /// `None` when the track has no mixer block.
+    #[serde(default)]
+    pub volume: Option<f64>,
+    /// Pan position from -1.0 (hard left) to 1.0 (hard right), from the
+    /// track's `Mixer`/`Pan`/`Manual` value. `None` when the track has no
+    /// mixer block.
+    #[serde(default)]
+    pub pan: Option<f64>,
+    /// Where this track's audio output goes ("Master", "Group Drums", an
+    /// external output, ...), from `AudioOutputRouting`'s
+    /// `UpperDisplayString`. `None` for MIDI tracks and tracks with no
+    /// audio output routing.
+    #[serde(default)]
+    pub output_routing: Option<String>,
+    /// Where this track's audio input comes from, from `AudioInputRouting`'s
+    /// `UpperDisplayString`. `None` for MIDI tracks and tracks with no audio
+    /// input routing.
+    #[serde(default)]
+    pub input_routing: Option<String>,
+    /// Crossfader A/B assignment, from the mixer's `CrossFadeState`. `None`
+    /// for no assignment (value `1`), an absent element, or files predating
+    /// this feature.
+    #[serde(default)]
+    pub crossfade: Option<CrossfadeAssign>,
+    /// Freeze state, from `Freeze`/`Value` or, on older saves, the mere
+    /// presence of a `FreezeStart`/`FreezeEnd` pair. `Some(false)` when the
+    /// track has a `Freeze` element but isn't frozen, matching
+    /// [`Track::muted`]/[`Track::soloed`]/[`Track::armed`]'s "element present,
+    /// flag off" convention. `None` when no freeze state is present at all.
+    #[serde(default)]
+    pub frozen: Option<bool>,
+    /// Target parameter IDs with an automation envelope, from
+    /// `AutomationEnvelopes`/`Envelopes`/`AutomationEnvelope`'s `PointeeId`.
+    /// Presence only — the envelope's recorded points aren't parsed.
+    #[serde(default)]
+    pub automated_params: Vec<String>,
+    /// Track delay for micro-timing/phase alignment, from the mixer's
+    /// `TrackDelay`/`Value`. Unit is milliseconds unless
+    /// [`Track::delay_is_samples`] is `Some(true)`. `None` when the track has
+    /// no delay set.
+    #[serde(default)]
+    pub track_delay: Option<f64>,
+    /// Whether [`Track::track_delay`] is in samples rather than milliseconds,
+    /// from `TrackDelay`/`IsValueSampleBased`. `None` when no delay is set.
+    #[serde(default)]
+    pub delay_is_samples: Option<bool>,
+    /// Producer's freeform note on the track, from `Annotation`/`Value`.
+    /// `None` when the element is absent or empty, mirroring
+    /// [`Track::user_name`]'s "empty string means unset" handling.
+    #[serde(default)]
+    pub comment: Option<String>,
+}
+
+/// Live's 70-entry track/clip color palette, indexed by the integer stored
+/// in a track's `Color` element. Each entry is a bare `RRGGBB` hex string.
+pub const LIVE_COLOR_PALETTE: [&str; 70] = [
+    "FF4C4C", "FF6B00", "FF9900", "FFB200", "FFD300", "F6F120", "CDE000", "A1E000", "55E000", "00E05B", "00E0A8",
+    "00E0D3", "00CFE0", "00A7E0", "0087E0", "0062E0", "0045E0", "2F3CE0", "6B3CE0", "9B3CE0", "C33CE0", "E03CC3",
+    "E03C8E", "E03C5E", "B35050", "B36E2E", "B38A2E", "B3A12E", "B3B82E", "9FA82E", "7FA82E", "5CA82E", "2EA84F",
+    "2EA87E", "2EA8A0", "2EA8C4", "2E8FA8", "2E6FA8", "2E52A8", "2E3AA8", "4A2EA8", "6F2EA8", "912EA8", "A82E8F",
+    "A82E63", "A82E3E", "F07575", "F09257", "F0AC57", "F0C157", "F0D457", "DCE06C", "B8E06C", "93E06C", "67E06C",
+    "6CE0A0", "6CE0C4", "6CE0E0", "6CC4E0", "6CA0E0", "6C80E0", "6C63E0", "8A6CE0", "B06CE0", "D06CE0", "E06CC2",
+    "E06C99", "E06C78", "D9D9D9", "7A7A7A",
+];
+
+impl Track {
+    pub fn new(id: impl Into<String>, track_type: TrackType) -> Self {
+        Track {
+            id: id.into(),
+            track_type,
+            user_name: None,
+            effective_name: String::new(),
+            branches: Vec::new(),
+            comp_sources: Vec::new(),
+            sends_only: None,
+            group_id: None,
+            clips: Vec::new(),
+            sends: Vec::new(),
+            pdc_enabled: None,
+            mappings: Vec::new(),
+            muted: None,
+            soloed: None,
+            armed: None,
+            color: None,
+            devices: Vec::new(),
+            volume: None,
+            pan: None,
+            output_routing: None,
+            input_routing: None,
+            crossfade: None,
+            frozen: None,
+            automated_params: Vec::new(),
+            track_delay: None,
+            delay_is_samples: None,
+            comment: None,
+        }
+    }
+
+    /// Resolves [`Track::color`] to a `#RRGGBB` string via
+    /// [`LIVE_COLOR_PALETTE`]. `None` if the track has no color or the index
+    /// is out of range.
+    pub fn color_hex(&self) -> Option<String> {
+        let index = self.color?;
+        LIVE_COLOR_PALETTE.get(index as usize).map(|hex| format!("#{hex}"))
+    }
+
+    pub fn set_user_name(&mut self, value: &str) {
+        self.user_name = Some(value.to_string());
+    }
+
+    pub fn set_effective_name(&mut self, value: &str) {
+        self.effective_name = value.to_string();
+    }
+}
+
+/// The parsed contents of an `.als` file.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Project {
+    #[serde(default)]
+    pub tracks: Vec<Track>,
+    /// Whether the metronome click is enabled, from `<Transport>`.
+    #[serde(default)]
+    pub metronome: Option<bool>,
+    /// Count-in length in bars, from `<Transport>`.
+    #[serde(default)]
+    pub count_in: Option<i32>,
+    /// Control-surface (MIDI controller) device names assigned to the set,
+    /// from `<LockedScripts>`.
+    #[serde(default)]
+    pub control_surfaces: Vec<String>,
+    /// The master tempo in BPM, from `MasterTrack`'s `<Tempo><Manual>`.
+    #[serde(default, rename = "Tempo")]
+    pub tempo: Option<f64>,
+    /// Whether the master tempo is automated over the arrangement, from
+    /// `MasterTrack`'s `<Tempo><AutomationTarget>` envelope having more
+    /// than one point. `false` for a static-tempo project.
+    #[serde(default)]
+    pub tempo_automated: bool,
+    /// The time signature as `"N/D"`, from `MasterTrack`'s `<TimeSignature>`.
+    #[serde(default, rename = "TimeSignature")]
+    pub time_signature: Option<String>,
+    /// A creation/last-modified timestamp embedded in the set, if any.
+    ///
+    /// Ableton's `.als` schema doesn't actually embed one today (saves are
+    /// timestamped by the filesystem only), so the parser never sets this —
+    /// it's here so a library browser can sort by it without caring whether
+    /// a future schema version adds one. Always `None` until then.
+    #[serde(default)]
+    pub last_modified: Option<String>,
+    /// Every external sample file this project depends on, deduplicated, in
+    /// first-seen order. Populated from `SampleRef`/`FileRef` elements'
+    /// `Path`/`RelativePath` values — whichever form is present in the file.
+    #[serde(default)]
+    pub samples: Vec<String>,
+    /// The `Creator` attribute of the root `<Ableton>` element (e.g.
+    /// `"Ableton Live 11.3.4"`), identifying the Live version that saved
+    /// the file. `None` if the attribute was absent.
+    #[serde(default)]
+    pub creator: Option<String>,
+    /// Arrangement cue points, from `Locators`/`Locator`, in the order Live
+    /// stores them.
+    #[serde(default)]
+    pub locators: Vec<Locator>,
+    /// Groove names in the project's groove pool, from `GroovePool`/
+    /// `Grooves`/`Groove`'s `Name`/`Value`. Empty for projects with no groove
+    /// pool or an empty one.
+    #[serde(default)]
+    pub grooves: Vec<String>,
+    /// The master track, reusing [`Track`] (`track_type` is always
+    /// [`TrackType::Master`]) so its devices/branches parse the same way as
+    /// a regular track's. `None` only if the file has no `MasterTrack`
+    /// element at all. Never appears in [`Project::tracks`].
+    #[serde(default)]
+    pub master: Option<Track>,
+    /// Session view scenes, from `Scenes`/`Scene`, in document order.
+    #[serde(default)]
+    pub scenes: Vec<Scene>,
+    /// The set's arrangement length in beats, computed as the furthest point
+    /// any clip on the Arrangement timeline reaches (its `Time` position plus
+    /// its trimmed content length). `None` for a session-only project with
+    /// no arrangement clips.
+    #[serde(default)]
+    pub length_beats: Option<f64>,
+}
+
+/// An arrangement cue point ("Intro", "Drop", ...), from `Locators`/`Locator`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Locator {
+    /// Position in beats.
+    pub time: f64,
+    pub name: String,
+}
+
+/// A Session view scene, from `Scenes`/`Scene`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Scene {
+    pub name: String,
+    /// Tempo override for this scene in BPM, from `Scene`/`Tempo`. `None`
+    /// when the scene follows the project tempo.
+    #[serde(default)]
+    pub tempo: Option<f64>,
+    /// Index into Live's fixed color palette ([`LIVE_COLOR_PALETTE`]), from
+    /// the scene's `Color` element. `None` when the scene uses no color
+    /// override.
+    #[serde(default)]
+    pub color: Option<u8>,
+}
+
+impl Project {
+    pub fn new() -> Self {
+        Project {
+            tracks: Vec::new(),
+            metronome: None,
+            count_in: None,
+            control_surfaces: Vec::new(),
+            tempo: None,
+            tempo_automated: false,
+            time_signature: None,
+            last_modified: None,
+            samples: Vec::new(),
+            creator: None,
+            locators: Vec::new(),
+            grooves: Vec::new(),
+            master: None,
+            scenes: Vec::new(),
+            length_beats: None,
+        }
+    }
+
+    /// Loads a `Project` snapshot previously written with
+    /// `serde_json::to_writer`/`to_string`, e.g. the cached "old" side of a
+    /// diff. New fields are `#[serde(default)]` throughout so a snapshot
+    /// written before they existed still deserializes.
+    pub fn from_json_path(path: &str) -> Result<Project, ParseError> {
+        let file = File::open(path)?;
+        Ok(serde_json::from_reader(file)?)
+    }
+
+    /// Reconstructs the group-track nesting from each track's `group_id`,
+    /// ignoring the flat storage order. Tracks whose `group_id` doesn't
+    /// resolve to a known track (an orphaned reference) are placed at the
+    /// top level rather than dropped.
+    pub fn track_tree(&self) -> Vec<TrackNode<'_>> {
+        let known_ids: std::collections::HashSet<&str> =
+            self.tracks.iter().map(|t| t.id.as_str()).collect();
+
+        let is_top_level = |t: &Track| match t.group_id.as_deref() {
+            None => true,
+            Some("-1") => true,
+            Some(id) => !known_ids.contains(id),
+        };
+
+        self.tracks
+            .iter()
+            .filter(|t| is_top_level(t))
+            .map(|t| TrackNode {
+                track: t,
+                children: children_of(&self.tracks, &t.id),
+            })
+            .collect()
+    }
+
+    /// Tracks whose `effective_name` is empty, a sign the parse hit a schema
+    /// gap (or a legacy file never had one). Useful as a data-quality signal
+    /// for callers to flag an import as possibly incomplete.
+    pub fn tracks_missing_names(&self) -> Vec<&Track> {
+        self.tracks.iter().filter(|t| t.effective_name.is_empty()).collect()
+    }
+
+    /// Sends whose target return index has no corresponding return track
+    /// (e.g. a return was deleted but the send holder lingers), one warning
+    /// per dangling send.
+    pub fn dangling_sends(&self) -> Vec<String> {
+        let return_count = self.tracks.iter().filter(|t| t.track_type == TrackType::Return).count() as i32;
+
+        let mut warnings = Vec::new();
+        for track in &self.tracks {
+            for send in &track.sends {
+                if send.target_index < 0 || send.target_index >= return_count {
+                    warnings.push(format!("Track {}: send references missing return", track.id));
+                }
+            }
+        }
+        warnings
+    }
+
+    /// Like [`Project::dangling_sends`], but fails outright instead of
+    /// returning warnings, for callers running in strict validation mode.
+    pub fn check_sends_strict(&self) -> Result<(), Vec<String>> {
+        let warnings = self.dangling_sends();
+        if warnings.is_empty() {
+            Ok(())
+        } else {
+            Err(warnings)
+        }
+    }
+
+    /// Cross-checks this project's parsed track count against a quick
+    /// structural scan of `raw_xml` for `<AudioTrack|MidiTrack|ReturnTrack|
+    /// GroupTrack>` occurrences, to catch a track-start arm matching but the
+    /// track then getting dropped (e.g. it never got an `Id`).
+    pub fn integrity_check(&self, raw_xml: &str) -> IntegrityReport {
+        const TRACK_TAGS: &[&str] = &["<AudioTrack", "<MidiTrack", "<ReturnTrack", "<GroupTrack"];
+        let raw_track_count = TRACK_TAGS.iter().map(|tag| raw_xml.matches(tag).count()).sum();
+
+        IntegrityReport {
+            parsed_track_count: self.tracks.len(),
+            raw_track_count,
+        }
+    }
+}
+
+fn children_of<'a>(tracks: &'a [Track], parent_id: &str) -> Vec<TrackNode<'a>> {
+    tracks
+        .iter()
+        .filter(|t| t.group_id.as_deref() == Some(parent_id))
+        .map(|t| TrackNode {
+            track: t,
+            children: children_of(tracks, &t.id),
+        })
+        .collect()
+}
+
+impl Default for Project {
+    fn default() -> Self {
+        Project::new()
+    }
+}
+
+/// Result of cross-checking [`Project::integrity_check`]'s parsed track
+/// count against a quick structural scan of the raw XML.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IntegrityReport {
+    pub parsed_track_count: usize,
+    pub raw_track_count: usize,
+}
+
+/// Size/timing diagnostics for a single parse, from
+/// [`crate::parse::parse_project_with_metrics`], for monitoring parse
+/// performance across a large library of sets.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ParseMetrics {
+    /// The `.als` file's on-disk size.
+    pub compressed_bytes: u64,
+    /// The size of the XML document after gunzipping (equal to
+    /// `compressed_bytes` for an already-uncompressed `.als`).
+    pub decompressed_bytes: u64,
+    /// Wall-clock time spent decompressing and parsing the opened file into
+    /// the finished [`Project`]; opening the file and peeking at its first
+    /// bytes are not counted.
+    pub parse_micros: u128,
+    /// Number of tracks in the parsed [`Project`].
+    pub track_count: usize,
+}
+
+impl IntegrityReport {
+    /// Whether the parse accounted for every track tag the raw scan found.
+    /// A mismatch usually means a track-start arm matched but the track was
+    /// later dropped (e.g. it never got an `Id`).
+    pub fn matches(&self) -> bool {
+        self.parsed_track_count == self.raw_track_count
+    }
+}
+
+/// A track together with the tracks grouped under it, as reconstructed by
+/// [`Project::track_tree`].
+#[derive(Debug)]
+pub struct TrackNode<'a> {
+    pub track: &'a Track,
+    pub children: Vec<TrackNode<'a>>,
+}
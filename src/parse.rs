@@ -0,0 +1,1576 @@
+//! XML parsing of decompressed Ableton Live Set documents.
+//!
+//! Behind the `logging` feature, the parser emits `trace!`/`debug!` events
+//! for recognized track/branch starts, unrecognized elements, and context
+//! transitions, via the `ptrace!`/`pdebug!` macros below. With the feature
+//! off, these calls vanish at the token level and the `log` crate isn't
+//! pulled in at all.
+
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashMap;
+use std::fs::File;
+use std::hash::{Hash, Hasher};
+use std::io::{BufRead, BufReader};
+
+use flate2::read::GzDecoder;
+use quick_xml::events::attributes::Attribute;
+use quick_xml::events::Event;
+use quick_xml::Reader;
+
+use crate::error::ParseError;
+use crate::model::{
+    AbletonMeta, Branch, Clip, ClipType, ClipView, CompressorParams, CrossfadeAssign, DelayParams, Locator, Macro,
+    Note, ParseMetrics, Project, Scene, SaturatorParams, Track, TrackSend, TrackType, WarpMarker,
+};
+use crate::reader::GZIP_MAGIC;
+
+/// Emits a `trace!` event when the `logging` feature is enabled; compiles
+/// away entirely otherwise.
+macro_rules! ptrace {
+    ($($arg:tt)*) => {
+        #[cfg(feature = "logging")]
+        log::trace!($($arg)*);
+    };
+}
+
+/// Emits a `debug!` event when the `logging` feature is enabled; compiles
+/// away entirely otherwise.
+macro_rules! pdebug {
+    ($($arg:tt)*) => {
+        #[cfg(feature = "logging")]
+        log::debug!($($arg)*);
+    };
+}
+
+/// Decodes an attribute's `Value`, resolving XML entities (`&amp;`, `&#39;`,
+/// ...) via quick-xml's unescape so a name like `Bass &amp; Drums` stores as
+/// `Bass & Drums` instead of the literal escape. Falls back to a lossy UTF-8
+/// decode of the raw bytes only if unescaping fails.
+fn unescape_attr_value(attr: &Attribute) -> String {
+    attr.unescape_value()
+        .map(|value| value.into_owned())
+        .unwrap_or_else(|_| String::from_utf8_lossy(&attr.value).to_string())
+}
+
+const BRANCH_ELEMENTS: &[&str] = &[
+    "AudioEffectBranch",
+    "InstrumentBranch",
+    "DrumBranch",
+    "MidiEffectBranch",
+    // Max for Live devices don't sit inside a rack branch of their own, but
+    // recognizing them as branches lets M4L-heavy chains show up at all
+    // instead of looking empty.
+    "MxDeviceAudioEffect",
+    "MxDeviceInstrument",
+    "MxDeviceMidiEffect",
+];
+const TRACK_ELEMENTS: &[&str] = &["AudioTrack", "MidiTrack", "ReturnTrack", "GroupTrack"];
+/// Native device tags recognized on a track's top-level `DeviceChain` (i.e.
+/// outside any rack branch), pushed verbatim into `Track::devices`. Devices
+/// with their own dedicated parameter parsing (`Compressor2`, `Delay`,
+/// `Saturator`) are recorded separately, alongside their params, rather than
+/// through this list.
+const NATIVE_DEVICE_ELEMENTS: &[&str] = &[
+    "Eq8",
+    "Reverb",
+    "AutoFilter",
+    "Utility",
+    "Gate",
+    "MultibandDynamics",
+    "GlueCompressor",
+    "Limiter",
+    "Chorus",
+    "Phaser",
+    "Tuner",
+];
+/// Plugin wrapper elements whose device name comes from a nested
+/// `PlugName`/`FileName`, rather than the tag itself.
+const PLUGIN_DEVICE_ELEMENTS: &[&str] = &["PluginDevice", "AuPluginDevice"];
+const CLIP_ELEMENTS: &[&str] = &["MidiClip", "AudioClip"];
+
+/// Elements that names-only parsing skips wholesale via
+/// [`quick_xml::Reader::read_to_end_into`], instead of descending into them
+/// event by event. These are exactly the subtrees `ParseConfig::names_only`
+/// callers don't need: clip data, device chains, and the mixer.
+const SKIPPABLE_IN_NAMES_ONLY: &[&str] = &["DeviceChain", "ClipSlotsList", "Slots", "MainSequencer", "Mixer"];
+
+/// Capacity of the `BufReader` layered over the `GzDecoder` in
+/// [`open_xml_reader`]. Multi-hundred-MB decompressed XML (large orchestral
+/// templates) otherwise pays for a lot of small reads at the default 8 KiB
+/// `BufReader` size.
+const GZIP_BUFFER_CAPACITY: usize = 64 * 1024;
+
+/// Tunables for the fast, partial-parse entry points.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ParseConfig {
+    /// Skip clip/device/mixer subtrees entirely and return only track
+    /// identity (`id`, `track_type`, names). Dramatically faster for
+    /// indexing workloads that only need track names across many files.
+    pub names_only: bool,
+}
+
+/// Which track types [`parse_project_with_options`] parses. An excluded
+/// track is skipped wholesale (via [`quick_xml::Reader::read_to_end_into`])
+/// rather than parsed and discarded, so indexing tools that only care about
+/// one track type don't pay for the others' device chains. Group and
+/// master tracks are always parsed, since they carry no device chain of
+/// their own to skip.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ParseOptions {
+    pub include_audio: bool,
+    pub include_midi: bool,
+    pub include_return: bool,
+    /// Whether to descend into rack branches (`InstrumentBranch`,
+    /// `AudioEffectBranch`, etc.) on tracks that are otherwise included.
+    pub include_branches: bool,
+}
+
+impl Default for ParseOptions {
+    fn default() -> Self {
+        ParseOptions {
+            include_audio: true,
+            include_midi: true,
+            include_return: true,
+            include_branches: true,
+        }
+    }
+}
+
+/// Whether `opts` keeps a track of this type. Group and master tracks have
+/// no per-type filter since they hold no device chain of their own.
+fn track_type_included(track_type: TrackType, opts: &ParseOptions) -> bool {
+    match track_type {
+        TrackType::Audio => opts.include_audio,
+        TrackType::Midi => opts.include_midi,
+        TrackType::Return => opts.include_return,
+        TrackType::Group | TrackType::Master => true,
+    }
+}
+
+/// Parses an `.als` file at `path` into a [`Project`].
+///
+/// Returns `Err` rather than panicking when the file can't be opened, isn't
+/// valid gzip, or contains malformed XML.
+pub fn get_project_from_als(path: &str) -> Result<Project, ParseError> {
+    let file = File::open(path)?;
+    parse_project_from_reader(BufReader::new(file))
+}
+
+/// A `Read` wrapper that tallies bytes as they flow through `inner`, used by
+/// [`parse_project_with_metrics`] to measure decompressed XML size without
+/// buffering the whole document up front just to call `.len()` on it.
+struct CountingReader<R> {
+    inner: R,
+    count: std::rc::Rc<std::cell::Cell<u64>>,
+}
+
+impl<R: std::io::Read> std::io::Read for CountingReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        let n = self.inner.read(buf)?;
+        self.count.set(self.count.get() + n as u64);
+        Ok(n)
+    }
+}
+
+/// Like [`get_project_from_als`], but also returns [`ParseMetrics`] — file
+/// size, decompressed XML size, wall-clock parse time, and track count —
+/// for performance monitoring across a large library of sets.
+pub fn parse_project_with_metrics(path: &str) -> Result<(Project, ParseMetrics), ParseError> {
+    let compressed_bytes = std::fs::metadata(path)?.len();
+    let mut reader = BufReader::new(File::open(path)?);
+
+    let is_gzip = {
+        let peek = reader.fill_buf()?;
+        peek.len() >= 2 && peek[..2] == GZIP_MAGIC[..]
+    };
+
+    let decompressed_bytes = std::rc::Rc::new(std::cell::Cell::new(0u64));
+    let start = std::time::Instant::now();
+    let project = if is_gzip {
+        let counting = CountingReader {
+            inner: GzDecoder::new(reader),
+            count: std::rc::Rc::clone(&decompressed_bytes),
+        };
+        parse_project_from_reader(BufReader::new(counting))?
+    } else {
+        // Nothing to decompress: the XML is exactly the bytes on disk.
+        decompressed_bytes.set(compressed_bytes);
+        parse_project_from_reader(reader)?
+    };
+    let parse_micros = start.elapsed().as_micros();
+
+    let metrics = ParseMetrics {
+        compressed_bytes,
+        decompressed_bytes: decompressed_bytes.get(),
+        parse_micros,
+        track_count: project.tracks.len(),
+    };
+
+    Ok((project, metrics))
+}
+
+/// Parses an in-memory `.als` buffer, for embedders (e.g. WASM) that already
+/// have the bytes and can't hand the parser a filesystem path.
+pub fn parse_project_from_bytes(data: &[u8]) -> Result<Project, ParseError> {
+    parse_project_from_reader(std::io::Cursor::new(data))
+}
+
+/// Peeks at `reader` to tell gzip-compressed `.als` bytes apart from plain,
+/// uncompressed XML, then builds a [`Reader`] over whichever form is
+/// present. Returns `Err` when `reader` looks like neither.
+fn open_xml_reader<R: BufRead>(mut reader: R) -> Result<Reader<Box<dyn BufRead + '_>>, ParseError> {
+    let is_gzip = {
+        let peek = reader.fill_buf()?;
+        peek.len() >= 2 && peek[..2] == GZIP_MAGIC[..]
+    };
+
+    // Some tools (and older/edge-case saves) write plain, uncompressed XML
+    // instead of gzip. Peeking for a leading `<` (after whitespace) lets us
+    // handle that transparently without an API change, while still
+    // rejecting genuinely corrupt input that's neither gzip nor XML.
+    let looks_like_xml = {
+        let peek = reader.fill_buf()?;
+        peek.iter().find(|b| !b.is_ascii_whitespace()).is_some_and(|&b| b == b'<')
+    };
+
+    if !is_gzip && !looks_like_xml {
+        return Err(ParseError::Gzip("not a gzip stream (bad or missing magic bytes)".to_string()));
+    }
+
+    let mut xml_reader: Reader<Box<dyn BufRead + '_>> = if is_gzip {
+        Reader::from_reader(Box::new(BufReader::with_capacity(GZIP_BUFFER_CAPACITY, GzDecoder::new(reader))))
+    } else {
+        Reader::from_reader(Box::new(reader))
+    };
+    xml_reader.trim_text(true);
+    Ok(xml_reader)
+}
+
+/// The gzip+XML pipeline shared by [`get_project_from_als`] and
+/// [`parse_project_from_bytes`]: `reader` yields the raw `.als` bytes,
+/// gzipped or plain XML.
+pub fn parse_project_from_reader<R: BufRead>(reader: R) -> Result<Project, ParseError> {
+    parse_project_from_reader_with_options(reader, &ParseOptions::default())
+}
+
+/// Like [`parse_project_from_reader`], but skips tracks (and, if requested,
+/// rack branches) excluded by `opts` instead of parsing and discarding
+/// them. Useful for indexing workloads that only care about one track type.
+pub fn parse_project_with_options<R: BufRead>(reader: R, opts: ParseOptions) -> Result<Project, ParseError> {
+    parse_project_from_reader_with_options(reader, &opts)
+}
+
+fn parse_project_from_reader_with_options<R: BufRead>(reader: R, opts: &ParseOptions) -> Result<Project, ParseError> {
+    let mut xml_reader = open_xml_reader(reader)?;
+
+    let mut project = Project::new();
+    let mut current_track: Option<Track> = None;
+    let mut current_clip: Option<Clip> = None;
+    let mut branch_stack: Vec<Branch> = Vec::new();
+    let mut in_clip_slot = false;
+    let mut clip_slot_has_clip = false;
+    let mut clip_slot_armed = false;
+    let mut in_arrangement_clips = false;
+    let mut current_clip_time = 0.0_f64;
+    let mut current_clip_start = 0.0_f64;
+    let mut current_clip_end = 0.0_f64;
+    let mut in_name_block = false;
+    let mut in_output_routing = false;
+    let mut in_input_routing = false;
+    let mut in_transport = false;
+    let mut in_compressor = false;
+    let mut compressor_params: (Option<f64>, Option<f64>, Option<f64>, Option<f64>) = (None, None, None, None);
+    let mut in_key_midi_mappings = false;
+    let mut in_delay = false;
+    let mut delay_params: (Option<bool>, Option<f64>, Option<f64>) = (None, None, None);
+    let mut in_sends = false;
+    let mut in_send = false;
+    let mut send_target: Option<i32> = None;
+    let mut send_amount: Option<f64> = None;
+    let mut in_saturator = false;
+    let mut saturator_params: (Option<f64>, Option<f64>) = (None, None);
+    let mut in_device_on = false;
+    let mut in_macro_control = false;
+    let mut macro_index: Option<usize> = None;
+    let mut in_locators = false;
+    let mut in_locator = false;
+    let mut locator_time: Option<f64> = None;
+    let mut locator_name: Option<String> = None;
+    let mut in_groove_pool = false;
+    let mut in_groove = false;
+    let mut in_automation_envelope = false;
+    let mut in_scenes = false;
+    let mut in_scene = false;
+    let mut scene_name: Option<String> = None;
+    let mut scene_tempo: Option<f64> = None;
+    let mut scene_color: Option<u8> = None;
+    let mut in_master_track = false;
+    let mut in_tempo = false;
+    let mut in_tempo_automation_events = false;
+    let mut tempo_automation_point_count: usize = 0;
+    let mut in_time_signature = false;
+    let mut time_signature_parts: (Option<i32>, Option<i32>) = (None, None);
+    let mut in_device_chain = false;
+    let mut in_plugin_device = false;
+    let mut plugin_device_name: Option<String> = None;
+    let mut in_plugin_data = false;
+    let mut plugin_data_text = String::new();
+    let mut in_file_ref = false;
+    let mut file_ref_path: Option<String> = None;
+    let mut file_ref_relative_path: Option<String> = None;
+    let mut in_mixer = false;
+    let mut in_volume = false;
+    let mut in_pan = false;
+    let mut in_track_delay = false;
+    let mut in_warp_markers = false;
+    let mut in_key_track = false;
+    let mut current_midi_key: Option<u8> = None;
+    let mut unrecognized_count: u32 = 0;
+    let mut buf = Vec::new();
+
+    loop {
+        match xml_reader.read_event_into(&mut buf) {
+            Ok(Event::Start(ref e)) => {
+                let name = String::from_utf8_lossy(e.name().as_ref()).to_string();
+                if name == "Ableton" {
+                    if let Some(attr) = e.attributes().flatten().find(|a| a.key.as_ref() == b"Creator") {
+                        project.creator = Some(String::from_utf8_lossy(&attr.value).to_string());
+                    }
+                } else if TRACK_ELEMENTS.contains(&name.as_str()) {
+                    if let Some(track_type) = TrackType::from_element_name(&name) {
+                        if track_type_included(track_type, opts) {
+                            pdebug!("track start: {name}");
+                            current_track = Some(Track::new(String::new(), track_type));
+                        } else {
+                            let end = e.name().to_owned();
+                            let _ = xml_reader.read_to_end_into(end, &mut Vec::new());
+                        }
+                    }
+                } else if BRANCH_ELEMENTS.contains(&name.as_str()) {
+                    if opts.include_branches {
+                        pdebug!("branch push: {name}");
+                        branch_stack.push(Branch::new(name));
+                    } else {
+                        let end = e.name().to_owned();
+                        let _ = xml_reader.read_to_end_into(end, &mut Vec::new());
+                    }
+                } else if CLIP_ELEMENTS.contains(&name.as_str()) {
+                    if let Some(clip_type) = ClipType::from_element_name(&name) {
+                        let view = if in_arrangement_clips {
+                            ClipView::Arrangement
+                        } else {
+                            ClipView::Session
+                        };
+                        current_clip_time = e
+                            .attributes()
+                            .flatten()
+                            .find(|a| a.key.as_ref() == b"Time")
+                            .and_then(|a| String::from_utf8_lossy(&a.value).parse().ok())
+                            .unwrap_or(0.0);
+                        current_clip_start = 0.0;
+                        current_clip_end = 0.0;
+                        current_clip = Some(Clip::new(clip_type, view));
+                        clip_slot_has_clip = true;
+                    }
+                } else if name == "ArrangementClips" {
+                    in_arrangement_clips = true;
+                } else if name == "ClipSlot" {
+                    in_clip_slot = true;
+                    clip_slot_has_clip = false;
+                    clip_slot_armed = false;
+                } else if name == "Name" {
+                    ptrace!("name block enter");
+                    in_name_block = true;
+                } else if name == "AudioOutputRouting" {
+                    in_output_routing = true;
+                } else if name == "AudioInputRouting" {
+                    in_input_routing = true;
+                } else if name == "Transport" {
+                    in_transport = true;
+                } else if name == "Compressor2" {
+                    in_compressor = true;
+                    compressor_params = (None, None, None, None);
+                    if in_device_chain && branch_stack.is_empty() {
+                        if let Some(track) = current_track.as_mut() {
+                            track.devices.push(name.clone());
+                        }
+                    }
+                } else if name == "KeyMidiMappings" {
+                    in_key_midi_mappings = true;
+                } else if name == "Delay" {
+                    in_delay = true;
+                    delay_params = (None, None, None);
+                    if in_device_chain && branch_stack.is_empty() {
+                        if let Some(track) = current_track.as_mut() {
+                            track.devices.push(name.clone());
+                        }
+                    }
+                } else if name == "Sends" {
+                    in_sends = true;
+                } else if in_sends && name == "Send" {
+                    in_send = true;
+                    send_amount = None;
+                    send_target = e
+                        .attributes()
+                        .flatten()
+                        .find(|a| a.key.as_ref() == b"Id")
+                        .and_then(|a| String::from_utf8_lossy(&a.value).parse().ok());
+                } else if name == "Saturator" {
+                    in_saturator = true;
+                    saturator_params = (None, None);
+                    if in_device_chain && branch_stack.is_empty() {
+                        if let Some(track) = current_track.as_mut() {
+                            track.devices.push(name.clone());
+                        }
+                    }
+                } else if matches!(name.as_str(), "SampleRef" | "FileRef") {
+                    in_file_ref = true;
+                    file_ref_path = None;
+                    file_ref_relative_path = None;
+                } else if name == "DeviceChain" {
+                    in_device_chain = true;
+                } else if in_device_chain && branch_stack.is_empty() && NATIVE_DEVICE_ELEMENTS.contains(&name.as_str())
+                {
+                    if let Some(track) = current_track.as_mut() {
+                        track.devices.push(name.clone());
+                    }
+                } else if PLUGIN_DEVICE_ELEMENTS.contains(&name.as_str()) {
+                    in_plugin_device = true;
+                    plugin_device_name = None;
+                } else if in_plugin_device && matches!(name.as_str(), "Data" | "Buffer") {
+                    in_plugin_data = true;
+                    plugin_data_text.clear();
+                } else if name == "On" && !branch_stack.is_empty() {
+                    in_device_on = true;
+                } else if !branch_stack.is_empty() && name.starts_with("MacroControls.") {
+                    in_macro_control = true;
+                    macro_index = name["MacroControls.".len()..].parse().ok();
+                } else if name == "WarpMarkers" {
+                    in_warp_markers = true;
+                } else if name == "KeyTrack" {
+                    in_key_track = true;
+                    current_midi_key = None;
+                } else if name == "Locators" {
+                    in_locators = true;
+                } else if in_locators && name == "Locator" {
+                    in_locator = true;
+                    locator_name = None;
+                    locator_time = e
+                        .attributes()
+                        .flatten()
+                        .find(|a| a.key.as_ref() == b"Time")
+                        .and_then(|a| String::from_utf8_lossy(&a.value).parse().ok());
+                } else if name == "GroovePool" {
+                    in_groove_pool = true;
+                } else if in_groove_pool && name == "Groove" {
+                    in_groove = true;
+                } else if name == "AutomationEnvelope" {
+                    in_automation_envelope = true;
+                } else if name == "Scenes" {
+                    in_scenes = true;
+                } else if in_scenes && name == "Scene" {
+                    in_scene = true;
+                    scene_name = None;
+                    scene_tempo = None;
+                    scene_color = None;
+                } else if name == "Mixer" {
+                    in_mixer = true;
+                } else if in_mixer && name == "Volume" {
+                    in_volume = true;
+                } else if in_mixer && name == "Pan" {
+                    in_pan = true;
+                } else if in_mixer && name == "TrackDelay" {
+                    in_track_delay = true;
+                } else if name == "MasterTrack" {
+                    in_master_track = true;
+                    current_track = Some(Track::new(String::new(), TrackType::Master));
+                } else if in_master_track && name == "Tempo" {
+                    in_tempo = true;
+                    tempo_automation_point_count = 0;
+                } else if in_tempo && name == "Events" {
+                    in_tempo_automation_events = true;
+                } else if in_master_track && name == "TimeSignature" {
+                    in_time_signature = true;
+                    time_signature_parts = (None, None);
+                } else {
+                    unrecognized_count += 1;
+                    ptrace!("unrecognized element: {name}");
+                }
+            }
+            Ok(Event::Empty(ref e)) => {
+                let name = String::from_utf8_lossy(e.name().as_ref()).to_string();
+                if name == "Id" {
+                    if let Some(attr) = e.attributes().flatten().find(|a| a.key.as_ref() == b"Value") {
+                        let value = String::from_utf8_lossy(&attr.value).to_string();
+                        if let Some(track) = current_track.as_mut() {
+                            track.id = value;
+                        }
+                    }
+                } else if name == "TrackGroupId" {
+                    if let Some(attr) = e.attributes().flatten().find(|a| a.key.as_ref() == b"Value") {
+                        let value = String::from_utf8_lossy(&attr.value).to_string();
+                        if let Some(track) = current_track.as_mut() {
+                            track.group_id = if value == "-1" { None } else { Some(value) };
+                        }
+                    }
+                } else if name == "IsExpanded" {
+                    if let Some(attr) = e.attributes().flatten().find(|a| a.key.as_ref() == b"Value") {
+                        let value = String::from_utf8_lossy(&attr.value).to_string();
+                        if let Some(branch) = branch_stack.last_mut() {
+                            branch.expanded = Some(value == "true");
+                        }
+                    }
+                } else if name == "Take" {
+                    if let Some(attr) = e.attributes().flatten().find(|a| a.key.as_ref() == b"Name") {
+                        let value = String::from_utf8_lossy(&attr.value).to_string();
+                        if let Some(track) = current_track.as_mut() {
+                            track.comp_sources.push(value);
+                        }
+                    }
+                } else if in_compressor && matches!(name.as_str(), "Threshold" | "Ratio" | "Attack" | "Release") {
+                    if let Some(attr) = e.attributes().flatten().find(|a| a.key.as_ref() == b"Value") {
+                        let value: f64 = String::from_utf8_lossy(&attr.value).parse().unwrap_or(0.0);
+                        match name.as_str() {
+                            "Threshold" => compressor_params.0 = Some(value),
+                            "Ratio" => compressor_params.1 = Some(value),
+                            "Attack" => compressor_params.2 = Some(value),
+                            "Release" => compressor_params.3 = Some(value),
+                            _ => {}
+                        }
+                    }
+                } else if name == "DelayCompensation" {
+                    if let Some(attr) = e.attributes().flatten().find(|a| a.key.as_ref() == b"Value") {
+                        let value = String::from_utf8_lossy(&attr.value).to_string();
+                        if let Some(track) = current_track.as_mut() {
+                            track.pdc_enabled = Some(value == "true");
+                        }
+                    }
+                } else if name == "Speaker" {
+                    if let Some(attr) = e.attributes().flatten().find(|a| a.key.as_ref() == b"Value") {
+                        let value = String::from_utf8_lossy(&attr.value).to_string();
+                        if let Some(track) = current_track.as_mut() {
+                            track.muted = Some(value == "true");
+                        }
+                    }
+                } else if name == "Solo" {
+                    if let Some(attr) = e.attributes().flatten().find(|a| a.key.as_ref() == b"Value") {
+                        let value = String::from_utf8_lossy(&attr.value).to_string();
+                        if let Some(track) = current_track.as_mut() {
+                            track.soloed = Some(value == "true");
+                        }
+                    }
+                } else if name == "Arm" {
+                    if let Some(attr) = e.attributes().flatten().find(|a| a.key.as_ref() == b"Value") {
+                        let value = String::from_utf8_lossy(&attr.value).to_string();
+                        if let Some(track) = current_track.as_mut() {
+                            track.armed = Some(value == "true");
+                        }
+                    }
+                } else if name == "Freeze" {
+                    if let Some(attr) = e.attributes().flatten().find(|a| a.key.as_ref() == b"Value") {
+                        let value = String::from_utf8_lossy(&attr.value).to_string();
+                        if let Some(track) = current_track.as_mut() {
+                            track.frozen = Some(value == "true");
+                        }
+                    }
+                } else if matches!(name.as_str(), "FreezeStart" | "FreezeEnd") {
+                    if let Some(track) = current_track.as_mut() {
+                        track.frozen = Some(true);
+                    }
+                } else if name == "Annotation" && branch_stack.is_empty() && current_clip.is_none() {
+                    if let Some(attr) = e.attributes().flatten().find(|a| a.key.as_ref() == b"Value") {
+                        let value = String::from_utf8_lossy(&attr.value).to_string();
+                        if let Some(track) = current_track.as_mut() {
+                            if !value.is_empty() {
+                                track.comment = Some(value);
+                            }
+                        }
+                    }
+                } else if in_file_ref && name == "Path" {
+                    if let Some(attr) = e.attributes().flatten().find(|a| a.key.as_ref() == b"Value") {
+                        file_ref_path = Some(String::from_utf8_lossy(&attr.value).to_string());
+                    }
+                } else if in_file_ref && name == "RelativePath" {
+                    if let Some(attr) = e.attributes().flatten().find(|a| a.key.as_ref() == b"Value") {
+                        file_ref_relative_path = Some(String::from_utf8_lossy(&attr.value).to_string());
+                    }
+                } else if in_file_ref && name == "DefaultSampleRate" {
+                    if let Some(attr) = e.attributes().flatten().find(|a| a.key.as_ref() == b"Value") {
+                        if let Ok(value) = String::from_utf8_lossy(&attr.value).parse::<u32>() {
+                            if let Some(clip) = current_clip.as_mut() {
+                                clip.sample_rate = Some(value);
+                            }
+                        }
+                    }
+                } else if in_file_ref && name == "OriginalFileSize" {
+                    if let Some(attr) = e.attributes().flatten().find(|a| a.key.as_ref() == b"Value") {
+                        if let Ok(value) = String::from_utf8_lossy(&attr.value).parse::<u64>() {
+                            if let Some(clip) = current_clip.as_mut() {
+                                clip.original_file_size = Some(value);
+                            }
+                        }
+                    }
+                } else if in_file_ref && name == "OriginalCrc" {
+                    if let Some(attr) = e.attributes().flatten().find(|a| a.key.as_ref() == b"Value") {
+                        if let Ok(value) = String::from_utf8_lossy(&attr.value).parse::<u32>() {
+                            if let Some(clip) = current_clip.as_mut() {
+                                clip.original_crc = Some(value);
+                            }
+                        }
+                    }
+                } else if name == "PitchCoarse" {
+                    if let Some(attr) = e.attributes().flatten().find(|a| a.key.as_ref() == b"Value") {
+                        if let Ok(value) = String::from_utf8_lossy(&attr.value).parse::<i32>() {
+                            if let Some(clip) = current_clip.as_mut() {
+                                if clip.clip_type == ClipType::Audio {
+                                    clip.pitch_coarse = Some(value);
+                                }
+                            }
+                        }
+                    }
+                } else if name == "PitchFine" {
+                    if let Some(attr) = e.attributes().flatten().find(|a| a.key.as_ref() == b"Value") {
+                        if let Ok(value) = String::from_utf8_lossy(&attr.value).parse::<i32>() {
+                            if let Some(clip) = current_clip.as_mut() {
+                                if clip.clip_type == ClipType::Audio {
+                                    clip.pitch_fine = Some(value);
+                                }
+                            }
+                        }
+                    }
+                } else if name == "CurrentStart" && current_clip.is_some() {
+                    if let Some(attr) = e.attributes().flatten().find(|a| a.key.as_ref() == b"Value") {
+                        if let Ok(value) = String::from_utf8_lossy(&attr.value).parse::<f64>() {
+                            current_clip_start = value;
+                        }
+                    }
+                } else if name == "CurrentEnd" && current_clip.is_some() {
+                    if let Some(attr) = e.attributes().flatten().find(|a| a.key.as_ref() == b"Value") {
+                        if let Ok(value) = String::from_utf8_lossy(&attr.value).parse::<f64>() {
+                            current_clip_end = value;
+                        }
+                    }
+                } else if in_plugin_device && name == "PlugName" {
+                    if let Some(attr) = e.attributes().flatten().find(|a| a.key.as_ref() == b"Value") {
+                        plugin_device_name = Some(String::from_utf8_lossy(&attr.value).to_string());
+                    }
+                } else if in_plugin_device && name == "FileName" && plugin_device_name.is_none() {
+                    if let Some(attr) = e.attributes().flatten().find(|a| a.key.as_ref() == b"Value") {
+                        plugin_device_name = Some(String::from_utf8_lossy(&attr.value).to_string());
+                    }
+                } else if name == "Color" && current_clip.is_some() {
+                    if let Some(attr) = e.attributes().flatten().find(|a| a.key.as_ref() == b"Value") {
+                        if let Ok(index) = String::from_utf8_lossy(&attr.value).parse::<u8>() {
+                            if let Some(clip) = current_clip.as_mut() {
+                                clip.color = Some(index);
+                            }
+                        }
+                    }
+                } else if name == "Color" && !in_scene {
+                    if let Some(attr) = e.attributes().flatten().find(|a| a.key.as_ref() == b"Value") {
+                        if let Ok(index) = String::from_utf8_lossy(&attr.value).parse::<u8>() {
+                            if let Some(track) = current_track.as_mut() {
+                                track.color = Some(index);
+                            }
+                        }
+                    }
+                } else if name == "OriginalPath" {
+                    if let Some(attr) = e.attributes().flatten().find(|a| a.key.as_ref() == b"Value") {
+                        let value = String::from_utf8_lossy(&attr.value).to_string();
+                        if let Some(clip) = current_clip.as_mut() {
+                            clip.original_path = Some(value);
+                        }
+                    }
+                } else if name == "CurrentPath" {
+                    if let Some(attr) = e.attributes().flatten().find(|a| a.key.as_ref() == b"Value") {
+                        let value = String::from_utf8_lossy(&attr.value).to_string();
+                        if let Some(clip) = current_clip.as_mut() {
+                            clip.current_path = Some(value);
+                        }
+                    }
+                } else if name == "Ram" {
+                    if let Some(attr) = e.attributes().flatten().find(|a| a.key.as_ref() == b"Value") {
+                        let value = String::from_utf8_lossy(&attr.value).to_string();
+                        if let Some(clip) = current_clip.as_mut() {
+                            if clip.clip_type == ClipType::Audio {
+                                clip.ram_mode = Some(value == "true");
+                            }
+                        }
+                    }
+                } else if name == "HiQ" {
+                    if let Some(attr) = e.attributes().flatten().find(|a| a.key.as_ref() == b"Value") {
+                        let value = String::from_utf8_lossy(&attr.value).to_string();
+                        if let Some(clip) = current_clip.as_mut() {
+                            if clip.clip_type == ClipType::Audio {
+                                clip.hi_q = Some(value == "true");
+                            }
+                        }
+                    }
+                } else if name == "IsWarped" {
+                    if let Some(attr) = e.attributes().flatten().find(|a| a.key.as_ref() == b"Value") {
+                        let value = String::from_utf8_lossy(&attr.value).to_string();
+                        if let Some(clip) = current_clip.as_mut() {
+                            if let Some(warp) = clip.warp.as_mut() {
+                                warp.is_warped = value == "true";
+                            }
+                        }
+                    }
+                } else if name == "WarpMode" {
+                    if let Some(attr) = e.attributes().flatten().find(|a| a.key.as_ref() == b"Value") {
+                        let value: i32 = String::from_utf8_lossy(&attr.value).parse().unwrap_or(0);
+                        if let Some(clip) = current_clip.as_mut() {
+                            if let Some(warp) = clip.warp.as_mut() {
+                                warp.warp_mode = Some(value);
+                            }
+                        }
+                    }
+                } else if in_warp_markers && name == "WarpMarker" {
+                    let sec_time = e
+                        .attributes()
+                        .flatten()
+                        .find(|a| a.key.as_ref() == b"SecTime")
+                        .and_then(|a| String::from_utf8_lossy(&a.value).parse().ok());
+                    let beat_time = e
+                        .attributes()
+                        .flatten()
+                        .find(|a| a.key.as_ref() == b"BeatTime")
+                        .and_then(|a| String::from_utf8_lossy(&a.value).parse().ok());
+                    if let (Some(sec_time), Some(beat_time)) = (sec_time, beat_time) {
+                        if let Some(clip) = current_clip.as_mut() {
+                            if let Some(warp) = clip.warp.as_mut() {
+                                warp.markers.push(WarpMarker { sec_time, beat_time });
+                            }
+                        }
+                    }
+                } else if name == "Groove" {
+                    if let Some(attr) = e.attributes().flatten().find(|a| a.key.as_ref() == b"Value") {
+                        let value = String::from_utf8_lossy(&attr.value).to_string();
+                        if let Some(clip) = current_clip.as_mut() {
+                            if !value.is_empty() {
+                                clip.groove = Some(value);
+                            }
+                        }
+                    }
+                } else if in_clip_slot && name == "RecordingState" {
+                    if let Some(attr) = e.attributes().flatten().find(|a| a.key.as_ref() == b"Value") {
+                        let value = String::from_utf8_lossy(&attr.value).to_string();
+                        clip_slot_armed = value == "true";
+                    }
+                } else if in_key_midi_mappings && name == "MappingEntry" {
+                    if let Some(attr) = e.attributes().flatten().find(|a| a.key.as_ref() == b"Name") {
+                        let value = String::from_utf8_lossy(&attr.value).to_string();
+                        if let Some(track) = current_track.as_mut() {
+                            track.mappings.push(value);
+                        }
+                    }
+                } else if in_delay && name == "Sync" {
+                    if let Some(attr) = e.attributes().flatten().find(|a| a.key.as_ref() == b"Value") {
+                        let value = String::from_utf8_lossy(&attr.value).to_string();
+                        delay_params.0 = Some(value == "true");
+                    }
+                } else if in_delay && name == "DelayTime" {
+                    if let Some(attr) = e.attributes().flatten().find(|a| a.key.as_ref() == b"Value") {
+                        delay_params.1 = String::from_utf8_lossy(&attr.value).parse().ok();
+                    }
+                } else if in_delay && name == "Feedback" {
+                    if let Some(attr) = e.attributes().flatten().find(|a| a.key.as_ref() == b"Value") {
+                        delay_params.2 = String::from_utf8_lossy(&attr.value).parse().ok();
+                    }
+                } else if in_sends && name == "Send" {
+                    // Self-closing `<Send Id=".."/>`: an empty send holder
+                    // with no `Manual` value, so there's nothing to convert.
+                    if let Some(attr) = e.attributes().flatten().find(|a| a.key.as_ref() == b"Id") {
+                        if let (Ok(index), Some(track)) = (String::from_utf8_lossy(&attr.value).parse(), current_track.as_mut()) {
+                            track.sends.push(TrackSend { target_index: index, amount_db: None });
+                        }
+                    }
+                } else if in_send && name == "Manual" {
+                    if let Some(attr) = e.attributes().flatten().find(|a| a.key.as_ref() == b"Value") {
+                        send_amount = String::from_utf8_lossy(&attr.value)
+                            .parse::<f64>()
+                            .ok()
+                            .map(crate::model::linear_to_db);
+                    }
+                } else if in_saturator && name == "Drive" {
+                    if let Some(attr) = e.attributes().flatten().find(|a| a.key.as_ref() == b"Value") {
+                        saturator_params.0 = String::from_utf8_lossy(&attr.value).parse().ok();
+                    }
+                } else if in_saturator && name == "Output" {
+                    if let Some(attr) = e.attributes().flatten().find(|a| a.key.as_ref() == b"Value") {
+                        saturator_params.1 = String::from_utf8_lossy(&attr.value).parse().ok();
+                    }
+                } else if in_device_on && name == "Manual" {
+                    if let Some(attr) = e.attributes().flatten().find(|a| a.key.as_ref() == b"Value") {
+                        if let Some(branch) = branch_stack.last_mut() {
+                            branch.enabled = Some(String::from_utf8_lossy(&attr.value) == "true");
+                        }
+                    }
+                } else if in_macro_control && name == "Manual" {
+                    if let Some(attr) = e.attributes().flatten().find(|a| a.key.as_ref() == b"Value") {
+                        if let (Some(index), Some(branch)) = (macro_index, branch_stack.last_mut()) {
+                            if let Ok(value) = String::from_utf8_lossy(&attr.value).parse() {
+                                branch.macro_slot_mut(index).value = value;
+                            }
+                        }
+                    }
+                } else if in_locator && name == "Name" {
+                    if let Some(attr) = e.attributes().flatten().find(|a| a.key.as_ref() == b"Value") {
+                        locator_name = Some(String::from_utf8_lossy(&attr.value).to_string());
+                    }
+                } else if in_groove && name == "Name" {
+                    if let Some(attr) = e.attributes().flatten().find(|a| a.key.as_ref() == b"Value") {
+                        let value = String::from_utf8_lossy(&attr.value).to_string();
+                        if !value.is_empty() {
+                            project.grooves.push(value);
+                        }
+                    }
+                } else if in_automation_envelope && name == "PointeeId" {
+                    if let Some(attr) = e.attributes().flatten().find(|a| a.key.as_ref() == b"Value") {
+                        let value = String::from_utf8_lossy(&attr.value).to_string();
+                        if let Some(track) = current_track.as_mut() {
+                            track.automated_params.push(value);
+                        }
+                    }
+                } else if in_scene && name == "Name" {
+                    if let Some(attr) = e.attributes().flatten().find(|a| a.key.as_ref() == b"Value") {
+                        scene_name = Some(String::from_utf8_lossy(&attr.value).to_string());
+                    }
+                } else if in_scene && name == "Tempo" {
+                    if let Some(attr) = e.attributes().flatten().find(|a| a.key.as_ref() == b"Value") {
+                        scene_tempo = String::from_utf8_lossy(&attr.value).parse().ok();
+                    }
+                } else if in_scene && name == "Color" {
+                    if let Some(attr) = e.attributes().flatten().find(|a| a.key.as_ref() == b"Value") {
+                        scene_color = String::from_utf8_lossy(&attr.value).parse().ok();
+                    }
+                } else if name.starts_with("MacroDisplayNames.") {
+                    if let Some(attr) = e.attributes().flatten().find(|a| a.key.as_ref() == b"Value") {
+                        let index: Option<usize> = name["MacroDisplayNames.".len()..].parse().ok();
+                        if let (Some(index), Some(branch)) = (index, branch_stack.last_mut()) {
+                            branch.macro_slot_mut(index).name = String::from_utf8_lossy(&attr.value).to_string();
+                        }
+                    }
+                } else if in_volume && name == "Manual" {
+                    if let Some(attr) = e.attributes().flatten().find(|a| a.key.as_ref() == b"Value") {
+                        if let Some(track) = current_track.as_mut() {
+                            track.volume = String::from_utf8_lossy(&attr.value).parse().ok();
+                        }
+                    }
+                } else if in_pan && name == "Manual" {
+                    if let Some(attr) = e.attributes().flatten().find(|a| a.key.as_ref() == b"Value") {
+                        if let Some(track) = current_track.as_mut() {
+                            track.pan = String::from_utf8_lossy(&attr.value).parse().ok();
+                        }
+                    }
+                } else if in_mixer && name == "CrossFadeState" {
+                    if let Some(attr) = e.attributes().flatten().find(|a| a.key.as_ref() == b"Value") {
+                        if let Some(track) = current_track.as_mut() {
+                            track.crossfade = match String::from_utf8_lossy(&attr.value).parse::<i32>().ok() {
+                                Some(0) => Some(CrossfadeAssign::A),
+                                Some(2) => Some(CrossfadeAssign::B),
+                                _ => None,
+                            };
+                        }
+                    }
+                } else if in_track_delay && name == "Value" {
+                    if let Some(attr) = e.attributes().flatten().find(|a| a.key.as_ref() == b"Value") {
+                        if let Some(track) = current_track.as_mut() {
+                            track.track_delay = String::from_utf8_lossy(&attr.value).parse().ok();
+                        }
+                    }
+                } else if in_track_delay && name == "IsValueSampleBased" {
+                    if let Some(attr) = e.attributes().flatten().find(|a| a.key.as_ref() == b"Value") {
+                        if let Some(track) = current_track.as_mut() {
+                            track.delay_is_samples = String::from_utf8_lossy(&attr.value).parse().ok();
+                        }
+                    }
+                } else if in_tempo && name == "Manual" {
+                    if let Some(attr) = e.attributes().flatten().find(|a| a.key.as_ref() == b"Value") {
+                        project.tempo = String::from_utf8_lossy(&attr.value).parse().ok();
+                    }
+                } else if in_tempo_automation_events {
+                    tempo_automation_point_count += 1;
+                } else if in_time_signature && name == "Numerator" {
+                    if let Some(attr) = e.attributes().flatten().find(|a| a.key.as_ref() == b"Value") {
+                        time_signature_parts.0 = String::from_utf8_lossy(&attr.value).parse().ok();
+                    }
+                } else if in_time_signature && name == "Denominator" {
+                    if let Some(attr) = e.attributes().flatten().find(|a| a.key.as_ref() == b"Value") {
+                        time_signature_parts.1 = String::from_utf8_lossy(&attr.value).parse().ok();
+                    }
+                } else if name == "ReceivingNote" {
+                    if let Some(attr) = e.attributes().flatten().find(|a| a.key.as_ref() == b"Value") {
+                        let value = String::from_utf8_lossy(&attr.value).to_string();
+                        if let Some(branch) = branch_stack.last_mut() {
+                            branch.receiving_note = value.parse().ok();
+                        }
+                    }
+                } else if in_key_track && name == "MidiKey" {
+                    if let Some(attr) = e.attributes().flatten().find(|a| a.key.as_ref() == b"Value") {
+                        current_midi_key = String::from_utf8_lossy(&attr.value).parse().ok();
+                    }
+                } else if name == "MidiNoteEvent" {
+                    let pitch = current_midi_key.or_else(|| {
+                        e.attributes()
+                            .flatten()
+                            .find(|a| a.key.as_ref() == b"Pitch")
+                            .and_then(|a| String::from_utf8_lossy(&a.value).parse().ok())
+                    });
+                    if let Some(pitch) = pitch {
+                        if let Some(clip) = current_clip.as_mut() {
+                            if clip.clip_type == ClipType::Midi {
+                                clip.note_pitches.push(pitch as i32);
+
+                                let time = e
+                                    .attributes()
+                                    .flatten()
+                                    .find(|a| a.key.as_ref() == b"Time")
+                                    .and_then(|a| String::from_utf8_lossy(&a.value).parse().ok());
+                                let duration = e
+                                    .attributes()
+                                    .flatten()
+                                    .find(|a| a.key.as_ref() == b"Duration")
+                                    .and_then(|a| String::from_utf8_lossy(&a.value).parse().ok());
+                                let velocity = e
+                                    .attributes()
+                                    .flatten()
+                                    .find(|a| a.key.as_ref() == b"Velocity")
+                                    .and_then(|a| String::from_utf8_lossy(&a.value).parse().ok());
+                                let mute = e
+                                    .attributes()
+                                    .flatten()
+                                    .find(|a| a.key.as_ref() == b"IsEnabled")
+                                    .and_then(|a| String::from_utf8_lossy(&a.value).parse::<bool>().ok())
+                                    .map_or(false, |is_enabled| !is_enabled);
+                                if let (Some(time), Some(duration), Some(velocity)) = (time, duration, velocity) {
+                                    clip.notes.push(Note { pitch, time, duration, velocity, mute });
+                                }
+                            }
+                        }
+                    }
+                } else if name == "ControlSurface" {
+                    if let Some(attr) = e.attributes().flatten().find(|a| a.key.as_ref() == b"Value") {
+                        let value = String::from_utf8_lossy(&attr.value).to_string();
+                        if !value.is_empty() {
+                            project.control_surfaces.push(value);
+                        }
+                    }
+                } else if in_transport && name == "Metronome" {
+                    if let Some(attr) = e.attributes().flatten().find(|a| a.key.as_ref() == b"Value") {
+                        let value = String::from_utf8_lossy(&attr.value).to_string();
+                        project.metronome = Some(value == "true");
+                    }
+                } else if in_transport && name == "CountIn" {
+                    if let Some(attr) = e.attributes().flatten().find(|a| a.key.as_ref() == b"Value") {
+                        let value = String::from_utf8_lossy(&attr.value).to_string();
+                        project.count_in = value.parse().ok();
+                    }
+                } else if in_output_routing && name == "Target" {
+                    if let Some(attr) = e.attributes().flatten().find(|a| a.key.as_ref() == b"Value") {
+                        let value = String::from_utf8_lossy(&attr.value).to_string();
+                        if let Some(track) = current_track.as_mut() {
+                            track.sends_only = Some(value.contains("SendsOnly"));
+                        }
+                    }
+                } else if in_output_routing && name == "UpperDisplayString" {
+                    if let Some(attr) = e.attributes().flatten().find(|a| a.key.as_ref() == b"Value") {
+                        let value = String::from_utf8_lossy(&attr.value).to_string();
+                        if let Some(track) = current_track.as_mut() {
+                            if !value.is_empty() {
+                                track.output_routing = Some(value);
+                            }
+                        }
+                    }
+                } else if in_input_routing && name == "UpperDisplayString" {
+                    if let Some(attr) = e.attributes().flatten().find(|a| a.key.as_ref() == b"Value") {
+                        let value = String::from_utf8_lossy(&attr.value).to_string();
+                        if let Some(track) = current_track.as_mut() {
+                            if !value.is_empty() {
+                                track.input_routing = Some(value);
+                            }
+                        }
+                    }
+                } else if in_name_block {
+                    if let Some(attr) = e.attributes().flatten().find(|a| a.key.as_ref() == b"Value") {
+                        let value = unescape_attr_value(&attr);
+                        if name == "EffectiveName" {
+                            if let Some(clip) = current_clip.as_mut() {
+                                clip.name = value;
+                            } else if let Some(branch) = branch_stack.last_mut() {
+                                branch.set_effective_name(&value);
+                            } else if let Some(track) = current_track.as_mut() {
+                                track.set_effective_name(&value);
+                            }
+                        } else if !value.is_empty() {
+                            if let Some(branch) = branch_stack.last_mut() {
+                                branch.set_user_name(&value);
+                            } else if let Some(track) = current_track.as_mut() {
+                                track.set_user_name(&value);
+                            }
+                        }
+                    }
+                }
+            }
+            Ok(Event::End(ref e)) => {
+                let name = String::from_utf8_lossy(e.name().as_ref()).to_string();
+                if name == "Name" {
+                    ptrace!("name block exit");
+                    in_name_block = false;
+                } else if name == "AudioOutputRouting" {
+                    in_output_routing = false;
+                } else if name == "AudioInputRouting" {
+                    in_input_routing = false;
+                } else if name == "Transport" {
+                    in_transport = false;
+                } else if name == "Compressor2" {
+                    in_compressor = false;
+                    if let (Some(threshold), Some(ratio), Some(attack), Some(release)) = compressor_params {
+                        if let Some(branch) = branch_stack.last_mut() {
+                            branch.compressor = Some(CompressorParams {
+                                threshold,
+                                ratio,
+                                attack,
+                                release,
+                            });
+                        }
+                    }
+                } else if name == "KeyMidiMappings" {
+                    in_key_midi_mappings = false;
+                } else if name == "DeviceChain" {
+                    in_device_chain = false;
+                } else if matches!(name.as_str(), "SampleRef" | "FileRef") {
+                    in_file_ref = false;
+                    if let Some(path) = file_ref_path.take().or_else(|| file_ref_relative_path.take()) {
+                        if !path.is_empty() && !project.samples.contains(&path) {
+                            project.samples.push(path);
+                        }
+                    }
+                } else if PLUGIN_DEVICE_ELEMENTS.contains(&name.as_str()) {
+                    in_plugin_device = false;
+                    if in_device_chain && branch_stack.is_empty() {
+                        if let Some(track) = current_track.as_mut() {
+                            track.devices.push(plugin_device_name.take().unwrap_or(name.clone()));
+                        }
+                    } else {
+                        plugin_device_name = None;
+                    }
+                } else if in_plugin_data && matches!(name.as_str(), "Data" | "Buffer") {
+                    in_plugin_data = false;
+                    if let Some(branch) = branch_stack.last_mut() {
+                        let trimmed = plugin_data_text.trim();
+                        if !trimmed.is_empty() {
+                            let mut hasher = DefaultHasher::new();
+                            trimmed.hash(&mut hasher);
+                            branch.state_hash = Some(hasher.finish());
+                        }
+                    }
+                } else if in_sends && name == "Send" {
+                    in_send = false;
+                    if let (Some(target_index), Some(track)) = (send_target.take(), current_track.as_mut()) {
+                        track.sends.push(TrackSend { target_index, amount_db: send_amount.take() });
+                    }
+                } else if name == "Sends" {
+                    in_sends = false;
+                } else if name == "Saturator" {
+                    in_saturator = false;
+                    if let (Some(drive), Some(output)) = saturator_params {
+                        if let Some(branch) = branch_stack.last_mut() {
+                            branch.saturator = Some(SaturatorParams { drive, output });
+                        }
+                    }
+                } else if name == "On" {
+                    in_device_on = false;
+                } else if name.starts_with("MacroControls.") {
+                    in_macro_control = false;
+                } else if in_locators && name == "Locator" {
+                    in_locator = false;
+                    if let (Some(time), Some(name)) = (locator_time.take(), locator_name.take()) {
+                        project.locators.push(Locator { time, name });
+                    }
+                } else if name == "Locators" {
+                    in_locators = false;
+                } else if name == "Groove" {
+                    in_groove = false;
+                } else if name == "GroovePool" {
+                    in_groove_pool = false;
+                } else if name == "AutomationEnvelope" {
+                    in_automation_envelope = false;
+                } else if in_scenes && name == "Scene" {
+                    in_scene = false;
+                    if let Some(scene_name) = scene_name.take() {
+                        project.scenes.push(Scene {
+                            name: scene_name,
+                            tempo: scene_tempo.take(),
+                            color: scene_color.take(),
+                        });
+                    }
+                } else if name == "Scenes" {
+                    in_scenes = false;
+                } else if name == "WarpMarkers" {
+                    in_warp_markers = false;
+                } else if name == "KeyTrack" {
+                    in_key_track = false;
+                } else if name == "Mixer" {
+                    in_mixer = false;
+                } else if name == "Volume" {
+                    in_volume = false;
+                } else if name == "Pan" {
+                    in_pan = false;
+                } else if name == "TrackDelay" {
+                    in_track_delay = false;
+                } else if name == "MasterTrack" {
+                    in_master_track = false;
+                    if let Some(mut master) = current_track.take() {
+                        // Devices/branches on the master chain are parsed the
+                        // same way as a regular track, so it can be left with
+                        // a leftover branch by the same kind of malformed
+                        // file `attach_leftover_branches` guards against
+                        // elsewhere.
+                        attach_leftover_branches(&mut branch_stack, &mut master);
+                        project.master = Some(master);
+                    }
+                } else if in_tempo_automation_events && name == "Events" {
+                    in_tempo_automation_events = false;
+                } else if name == "Tempo" {
+                    in_tempo = false;
+                    project.tempo_automated = tempo_automation_point_count > 1;
+                } else if name == "TimeSignature" {
+                    in_time_signature = false;
+                    if let (Some(numerator), Some(denominator)) = time_signature_parts {
+                        project.time_signature = Some(format!("{numerator}/{denominator}"));
+                    }
+                } else if name == "Delay" {
+                    in_delay = false;
+                    if let (Some(sync), Some(delay_time), Some(feedback)) = delay_params {
+                        if let Some(branch) = branch_stack.last_mut() {
+                            branch.delay = Some(DelayParams {
+                                sync,
+                                delay_time,
+                                feedback,
+                            });
+                        }
+                    }
+                } else if BRANCH_ELEMENTS.contains(&name.as_str()) {
+                    if let Some(mut finished) = branch_stack.pop() {
+                        pdebug!("branch pop: {}", finished.branch_type);
+                        finished.macros.retain(|m| !is_untouched_macro(m));
+                        if let Some(parent) = branch_stack.last_mut() {
+                            parent.branches.push(finished);
+                        } else if let Some(track) = current_track.as_mut() {
+                            track.branches.push(finished);
+                        }
+                    }
+                } else if CLIP_ELEMENTS.contains(&name.as_str()) {
+                    if let Some(mut clip) = current_clip.take() {
+                        if clip.view == ClipView::Arrangement {
+                            let end = current_clip_time + (current_clip_end - current_clip_start).max(0.0);
+                            project.length_beats = Some(project.length_beats.map_or(end, |max| max.max(end)));
+                            clip.start_time = Some(current_clip_time);
+                        }
+                        clip.loop_start = Some(current_clip_start);
+                        clip.loop_end = Some(current_clip_end);
+                        if let Some(track) = current_track.as_mut() {
+                            track.clips.push(clip);
+                        }
+                    }
+                } else if name == "ArrangementClips" {
+                    in_arrangement_clips = false;
+                } else if name == "ClipSlot" {
+                    in_clip_slot = false;
+                    if clip_slot_armed && !clip_slot_has_clip {
+                        if let Some(track) = current_track.as_mut() {
+                            track.clips.push(Clip::new(ClipType::Empty, ClipView::Session));
+                        }
+                    }
+                } else if TRACK_ELEMENTS.contains(&name.as_str()) {
+                    if let Some(mut track) = current_track.take() {
+                        // A branch left open by a malformed file (more opens
+                        // than closes) shouldn't linger on the stack and get
+                        // misattached to whichever track happens to close the
+                        // next branch of the same type; fold it into this
+                        // track now instead.
+                        attach_leftover_branches(&mut branch_stack, &mut track);
+                        resolve_drum_hits(&mut track);
+                        project.tracks.push(track);
+                    }
+                }
+            }
+            Ok(Event::Text(ref e)) if in_plugin_data => {
+                plugin_data_text.push_str(&e.unescape().unwrap_or_default());
+            }
+            Ok(Event::Eof) => break,
+            Err(e) => return Err(ParseError::Xml(e)),
+            _ => {}
+        }
+        buf.clear();
+    }
+
+    if unrecognized_count > 0 {
+        pdebug!("parse complete with {unrecognized_count} unrecognized elements");
+    }
+
+    if !branch_stack.is_empty() {
+        match current_track.take() {
+            Some(mut track) => {
+                attach_leftover_branches(&mut branch_stack, &mut track);
+                resolve_drum_hits(&mut track);
+                project.tracks.push(track);
+            }
+            None => {
+                return Err(ParseError::UnbalancedXml(format!(
+                    "{} branch(es) left open with no enclosing track at EOF",
+                    branch_stack.len()
+                )));
+            }
+        }
+    }
+
+    Ok(project)
+}
+
+/// Folds any branches still on `branch_stack` into `track`, preserving
+/// nesting the same way a well-formed End event would: each leftover attaches
+/// to its still-open parent, or to `track` directly once the stack bottoms
+/// out. Used to recover from a file with more branch opens than closes,
+/// instead of losing the data or leaving it to be misattached to whichever
+/// track closes the next same-named branch.
+fn attach_leftover_branches(branch_stack: &mut Vec<Branch>, track: &mut Track) {
+    while let Some(mut finished) = branch_stack.pop() {
+        finished.macros.retain(|m| !is_untouched_macro(m));
+        match branch_stack.last_mut() {
+            Some(parent) => parent.branches.push(finished),
+            None => track.branches.push(finished),
+        }
+    }
+}
+
+/// Whether a parsed macro slot was never actually touched by the user: still
+/// has its default `Macro N` name and its default zero value. Dropped from
+/// `Branch::macros` so a 16-knob rack with two customized macros doesn't
+/// carry 14 meaningless entries.
+fn is_untouched_macro(m: &Macro) -> bool {
+    m.value == 0.0 && m.name.strip_prefix("Macro ").is_some_and(|rest| rest.parse::<usize>().is_ok())
+}
+
+/// Builds a MIDI pitch -> drum-pad name map from a track's `DrumBranch`
+/// chains, recursing into nested racks.
+fn drum_pad_map(branches: &[Branch]) -> HashMap<i32, String> {
+    let mut map = HashMap::new();
+    for branch in branches {
+        if branch.branch_type == "DrumBranch" {
+            if let Some(note) = branch.receiving_note {
+                map.insert(note, branch.effective_name.clone());
+            }
+        }
+        map.extend(drum_pad_map(&branch.branches));
+    }
+    map
+}
+
+/// Resolves each MIDI clip's raw note pitches to drum-pad hit counts using
+/// the track's own `DrumBranch` mappings, then discards the raw pitches.
+fn resolve_drum_hits(track: &mut Track) {
+    let pad_map = drum_pad_map(&track.branches);
+    if pad_map.is_empty() {
+        for clip in &mut track.clips {
+            clip.note_pitches.clear();
+        }
+        return;
+    }
+
+    for clip in &mut track.clips {
+        let mut counts: HashMap<&str, usize> = HashMap::new();
+        for pitch in &clip.note_pitches {
+            if let Some(pad_name) = pad_map.get(pitch) {
+                *counts.entry(pad_name.as_str()).or_insert(0) += 1;
+            }
+        }
+        let mut hits: Vec<(String, usize)> = counts.into_iter().map(|(name, count)| (name.to_string(), count)).collect();
+        hits.sort();
+        clip.drum_hits = hits;
+        clip.note_pitches.clear();
+    }
+}
+
+/// Parses an `.als` file using `config`. When `config.names_only` is set,
+/// this skips entering clip/device/mixer subtrees entirely using
+/// quick-xml's `read_to_end_into`, rather than iterating every inner event,
+/// which is substantially faster on device-heavy projects.
+pub fn parse_project_with_config(path: &str, config: &ParseConfig) -> Result<Project, ParseError> {
+    if !config.names_only {
+        return get_project_from_als(path);
+    }
+
+    let file = File::open(path)?;
+    let reader = BufReader::with_capacity(GZIP_BUFFER_CAPACITY, file);
+    let mut xml_reader = open_xml_reader(reader)?;
+
+    let mut project = Project::new();
+    let mut current_track: Option<Track> = None;
+    let mut in_name_block = false;
+    let mut buf = Vec::new();
+
+    loop {
+        match xml_reader.read_event_into(&mut buf) {
+            Ok(Event::Start(ref e)) => {
+                let name = String::from_utf8_lossy(e.name().as_ref()).to_string();
+                if TRACK_ELEMENTS.contains(&name.as_str()) {
+                    if let Some(track_type) = TrackType::from_element_name(&name) {
+                        current_track = Some(Track::new(String::new(), track_type));
+                    }
+                } else if name == "Name" {
+                    in_name_block = true;
+                } else if SKIPPABLE_IN_NAMES_ONLY.contains(&name.as_str()) {
+                    let end = e.name().to_owned();
+                    let _ = xml_reader.read_to_end_into(end, &mut Vec::new());
+                }
+            }
+            Ok(Event::Empty(ref e)) => {
+                let name = String::from_utf8_lossy(e.name().as_ref()).to_string();
+                if name == "Id" {
+                    if let Some(attr) = e.attributes().flatten().find(|a| a.key.as_ref() == b"Value") {
+                        let value = String::from_utf8_lossy(&attr.value).to_string();
+                        if let Some(track) = current_track.as_mut() {
+                            track.id = value;
+                        }
+                    }
+                } else if in_name_block {
+                    if let Some(attr) = e.attributes().flatten().find(|a| a.key.as_ref() == b"Value") {
+                        let value = unescape_attr_value(&attr);
+                        if name == "EffectiveName" {
+                            if let Some(track) = current_track.as_mut() {
+                                track.set_effective_name(&value);
+                            }
+                        } else if !value.is_empty() {
+                            if let Some(track) = current_track.as_mut() {
+                                track.set_user_name(&value);
+                            }
+                        }
+                    }
+                }
+            }
+            Ok(Event::End(ref e)) => {
+                let name = String::from_utf8_lossy(e.name().as_ref()).to_string();
+                if name == "Name" {
+                    in_name_block = false;
+                } else if TRACK_ELEMENTS.contains(&name.as_str()) {
+                    if let Some(track) = current_track.take() {
+                        project.tracks.push(track);
+                    }
+                }
+            }
+            Ok(Event::Eof) => break,
+            Err(e) => return Err(ParseError::Xml(e)),
+            _ => {}
+        }
+        buf.clear();
+    }
+
+    Ok(project)
+}
+
+/// Walks `reader` the same way [`parse_project_from_reader`] enters the
+/// document (sharing [`open_xml_reader`]'s gzip/plain-XML detection), but
+/// calls `f` with each track as soon as its closing tag is seen instead of
+/// collecting them into a [`Project`]. Branch parsing is skipped entirely,
+/// since callers needing chain detail can use [`parse_project_from_reader`]
+/// or [`parse_with_handler`] instead. Intended for indexing workloads (e.g.
+/// scanning a large folder for track names) that would otherwise pay for a
+/// `Project` allocation per file they don't need.
+pub fn visit_tracks<R: BufRead, F: FnMut(&Track)>(reader: R, mut f: F) -> Result<(), ParseError> {
+    let mut xml_reader = open_xml_reader(reader)?;
+
+    let mut current_track: Option<Track> = None;
+    let mut in_name_block = false;
+    let mut buf = Vec::new();
+
+    loop {
+        match xml_reader.read_event_into(&mut buf)? {
+            Event::Start(ref e) => {
+                let name = String::from_utf8_lossy(e.name().as_ref()).to_string();
+                if TRACK_ELEMENTS.contains(&name.as_str()) {
+                    if let Some(track_type) = TrackType::from_element_name(&name) {
+                        current_track = Some(Track::new(String::new(), track_type));
+                    }
+                } else if name == "Name" {
+                    in_name_block = true;
+                } else if SKIPPABLE_IN_NAMES_ONLY.contains(&name.as_str()) || BRANCH_ELEMENTS.contains(&name.as_str())
+                {
+                    let end = e.name().to_owned();
+                    let _ = xml_reader.read_to_end_into(end, &mut Vec::new());
+                }
+            }
+            Event::Empty(ref e) => {
+                let name = String::from_utf8_lossy(e.name().as_ref()).to_string();
+                if name == "Id" {
+                    if let Some(attr) = e.attributes().flatten().find(|a| a.key.as_ref() == b"Value") {
+                        let value = String::from_utf8_lossy(&attr.value).to_string();
+                        if let Some(track) = current_track.as_mut() {
+                            track.id = value;
+                        }
+                    }
+                } else if in_name_block {
+                    if let Some(attr) = e.attributes().flatten().find(|a| a.key.as_ref() == b"Value") {
+                        let value = unescape_attr_value(&attr);
+                        if name == "EffectiveName" {
+                            if let Some(track) = current_track.as_mut() {
+                                track.set_effective_name(&value);
+                            }
+                        } else if !value.is_empty() {
+                            if let Some(track) = current_track.as_mut() {
+                                track.set_user_name(&value);
+                            }
+                        }
+                    }
+                }
+            }
+            Event::End(ref e) => {
+                let name = String::from_utf8_lossy(e.name().as_ref()).to_string();
+                if name == "Name" {
+                    in_name_block = false;
+                } else if TRACK_ELEMENTS.contains(&name.as_str()) {
+                    if let Some(track) = current_track.take() {
+                        f(&track);
+                    }
+                }
+            }
+            Event::Eof => break,
+            _ => {}
+        }
+        buf.clear();
+    }
+
+    Ok(())
+}
+
+/// Reads just the root `<Ableton>` element's attributes (`Version`,
+/// `Creator`) and returns as soon as they're known, without descending into
+/// `<Tracks>` at all. Dramatically faster than a full parse when auditing
+/// schema versions across a large folder of sets.
+pub fn parse_metadata_only<R: BufRead>(reader: R) -> Result<AbletonMeta, ParseError> {
+    let mut xml_reader = Reader::from_reader(reader);
+    xml_reader.trim_text(true);
+    scan_ableton_meta(&mut xml_reader)
+}
+
+/// Opens `path`, transparently handling gzip or plain XML, and reads just
+/// the root `<Ableton>` element's attributes. The cheapest way to check
+/// which Live version saved a file.
+pub fn get_ableton_meta(path: &str) -> Result<AbletonMeta, ParseError> {
+    let file = File::open(path)?;
+    let mut xml_reader = open_xml_reader(BufReader::new(file))?;
+    scan_ableton_meta(&mut xml_reader)
+}
+
+fn scan_ableton_meta<R: BufRead>(xml_reader: &mut Reader<R>) -> Result<AbletonMeta, ParseError> {
+    let mut buf = Vec::new();
+    loop {
+        match xml_reader.read_event_into(&mut buf)? {
+            Event::Start(ref e) | Event::Empty(ref e) if e.name().as_ref() == b"Ableton" => {
+                let mut meta = AbletonMeta::default();
+                for attr in e.attributes().flatten() {
+                    let value = String::from_utf8_lossy(&attr.value).to_string();
+                    match attr.key.as_ref() {
+                        b"Version" => meta.version = Some(value),
+                        b"Creator" => meta.creator = Some(value),
+                        _ => {}
+                    }
+                }
+                return Ok(meta);
+            }
+            Event::Eof => return Ok(AbletonMeta::default()),
+            _ => {}
+        }
+        buf.clear();
+    }
+}
+
+/// Hooks invoked while [`parse_with_handler`] walks the document, for
+/// callers who want to extract something the crate doesn't model without
+/// forking the parser.
+pub trait EventHandler {
+    /// Called once a `<AudioTrack>`/`<MidiTrack>`/`<ReturnTrack>`/`<GroupTrack>` has finished parsing.
+    fn on_track(&mut self, _track: &Track) {}
+    /// Called once a rack branch has finished parsing.
+    fn on_branch(&mut self, _branch: &Branch) {}
+    /// Called for every start/empty element, with its raw attributes.
+    fn on_element(&mut self, _name: &str, _attrs: &[(String, String)]) {}
+}
+
+/// Walks the document the same way [`get_project_from_als`] does, invoking
+/// `handler`'s hooks as it goes. Reuses the decompress/XML machinery and
+/// context tracking so custom extraction doesn't require forking the whole
+/// parser. The returned [`Project`] holds only the tracks, each with its id,
+/// display name and rack branches; nothing else is parsed in this mode.
+pub fn parse_with_handler<R: BufRead, H: EventHandler>(reader: R, handler: &mut H) -> Result<Project, ParseError> {
+    let mut xml_reader = open_xml_reader(reader)?;
+
+    let mut project = Project::new();
+    let mut current_track: Option<Track> = None;
+    let mut branch_stack: Vec<Branch> = Vec::new();
+    let mut in_clip = false;
+    let mut buf = Vec::new();
+
+    loop {
+        match xml_reader.read_event_into(&mut buf) {
+            Ok(Event::Start(ref e)) | Ok(Event::Empty(ref e)) => {
+                let name = String::from_utf8_lossy(e.name().as_ref()).to_string();
+                let attrs: Vec<(String, String)> = e
+                    .attributes()
+                    .flatten()
+                    .map(|a| {
+                        (
+                            String::from_utf8_lossy(a.key.as_ref()).to_string(),
+                            String::from_utf8_lossy(&a.value).to_string(),
+                        )
+                    })
+                    .collect();
+                handler.on_element(&name, &attrs);
+
+                if TRACK_ELEMENTS.contains(&name.as_str()) {
+                    if let Some(track_type) = TrackType::from_element_name(&name) {
+                        current_track = Some(Track::new(String::new(), track_type));
+                    }
+                } else if BRANCH_ELEMENTS.contains(&name.as_str()) {
+                    branch_stack.push(Branch::new(name));
+                } else if CLIP_ELEMENTS.contains(&name.as_str()) {
+                    in_clip = true;
+                } else if name == "Id" || (name == "EffectiveName" && !in_clip) {
+                    let value = e.attributes().flatten().find(|a| a.key.as_ref() == b"Value").map(|a| unescape_attr_value(&a));
+                    match (value, name.as_str(), branch_stack.last_mut(), current_track.as_mut()) {
+                        (Some(value), "Id", _, Some(track)) => track.id = value,
+                        (Some(value), "EffectiveName", Some(branch), _) => branch.set_effective_name(&value),
+                        (Some(value), "EffectiveName", None, Some(track)) => track.set_effective_name(&value),
+                        _ => {}
+                    }
+                }
+            }
+            Ok(Event::End(ref e)) => {
+                let name = String::from_utf8_lossy(e.name().as_ref()).to_string();
+                if CLIP_ELEMENTS.contains(&name.as_str()) {
+                    in_clip = false;
+                } else if BRANCH_ELEMENTS.contains(&name.as_str()) {
+                    if let Some(finished) = branch_stack.pop() {
+                        handler.on_branch(&finished);
+                        if let Some(parent) = branch_stack.last_mut() {
+                            parent.branches.push(finished);
+                        } else if let Some(track) = current_track.as_mut() {
+                            track.branches.push(finished);
+                        }
+                    }
+                } else if TRACK_ELEMENTS.contains(&name.as_str()) {
+                    if let Some(track) = current_track.take() {
+                        handler.on_track(&track);
+                        project.tracks.push(track);
+                    }
+                }
+            }
+            Ok(Event::Eof) => break,
+            Err(e) => return Err(e.into()),
+            _ => {}
+        }
+        buf.clear();
+    }
+
+    Ok(project)
+}
@@ -0,0 +1,30 @@
+//! Low-level byte/gzip plumbing shared by the various parsing entry points.
+
+use std::fs::File;
+use std::io::Read;
+
+use flate2::read::GzDecoder;
+
+use crate::error::ParseError;
+
+/// The two magic bytes that mark a gzip stream.
+pub const GZIP_MAGIC: [u8; 2] = [0x1f, 0x8b];
+
+fn decompress_gz<R: Read>(reader: R) -> Result<String, ParseError> {
+    let mut gz = GzDecoder::new(reader);
+    let mut out = String::new();
+    gz.read_to_string(&mut out)?;
+    Ok(out)
+}
+
+/// Gunzips the `.als` file at `path` and returns the raw decompressed XML,
+/// without parsing it. Useful for inspecting elements the parser ignores.
+pub fn decompress_als(path: &str) -> Result<String, ParseError> {
+    let file = File::open(path)?;
+    decompress_gz(file)
+}
+
+/// Same as [`decompress_als`] but operates on an in-memory `.als` buffer.
+pub fn decompress_als_bytes(data: &[u8]) -> Result<String, ParseError> {
+    decompress_gz(data)
+}
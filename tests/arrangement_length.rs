@@ -0,0 +1,45 @@
+//! `Project.length_beats`, the furthest point any Arrangement clip reaches,
+//! from `ArrangementClips`' clip `Time` position plus its trimmed
+//! `CurrentStart`/`CurrentEnd` content length.
+
+use als_parser_rs::parse_project_from_bytes;
+
+fn project_xml(track_body: &str) -> Vec<u8> {
+    format!("<Ableton><Tracks><AudioTrack><Id Value=\"1\"/>{track_body}</AudioTrack></Tracks></Ableton>").into_bytes()
+}
+
+#[test]
+fn session_only_project_has_no_length() {
+    let project = parse_project_from_bytes(&project_xml(
+        "<AudioClip><Name><EffectiveName Value=\"Loop\"/></Name></AudioClip>",
+    ))
+    .unwrap();
+
+    assert_eq!(project.length_beats, None);
+}
+
+#[test]
+fn length_is_the_furthest_arrangement_clip_end() {
+    let project = parse_project_from_bytes(&project_xml(
+        "<ArrangementClips>\
+           <AudioClip Time=\"0\"><CurrentStart Value=\"0\"/><CurrentEnd Value=\"8\"/></AudioClip>\
+           <MidiClip Time=\"16\"><CurrentStart Value=\"0\"/><CurrentEnd Value=\"4\"/></MidiClip>\
+         </ArrangementClips>",
+    ))
+    .unwrap();
+
+    assert_eq!(project.length_beats, Some(20.0));
+}
+
+#[test]
+fn session_clips_alongside_arrangement_clips_do_not_affect_length() {
+    let project = parse_project_from_bytes(&project_xml(
+        "<AudioClip><Name><EffectiveName Value=\"SessionLoop\"/></Name></AudioClip>\
+         <ArrangementClips>\
+           <MidiClip Time=\"2\"><CurrentStart Value=\"0\"/><CurrentEnd Value=\"2\"/></MidiClip>\
+         </ArrangementClips>",
+    ))
+    .unwrap();
+
+    assert_eq!(project.length_beats, Some(4.0));
+}
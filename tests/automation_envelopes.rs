@@ -0,0 +1,48 @@
+//! Automation envelope presence, from `AutomationEnvelopes`/`Envelopes`/
+//! `AutomationEnvelope`'s `PointeeId`.
+
+use als_parser_rs::parse_project_from_bytes;
+
+fn track_xml(envelopes: &str) -> Vec<u8> {
+    format!(
+        "<Ableton><Tracks><AudioTrack><Id Value=\"1\"/>\
+         <Name><EffectiveName Value=\"Filter\"/></Name>\
+         <DeviceChain><AutomationEnvelopes><Envelopes>{envelopes}</Envelopes></AutomationEnvelopes></DeviceChain>\
+         </AudioTrack></Tracks></Ableton>"
+    )
+    .into_bytes()
+}
+
+#[test]
+fn automated_param_ids_are_collected() {
+    let project = parse_project_from_bytes(&track_xml(
+        "<AutomationEnvelope Id=\"0\">\
+         <EnvelopeTarget><PointeeId Value=\"123\"/></EnvelopeTarget>\
+         <Automation><Events></Events></Automation>\
+         </AutomationEnvelope>",
+    ))
+    .unwrap();
+
+    assert_eq!(project.tracks[0].automated_params, vec!["123"]);
+}
+
+#[test]
+fn no_envelopes_yields_empty_vec() {
+    let project = parse_project_from_bytes(&track_xml("")).unwrap();
+
+    assert!(project.tracks[0].automated_params.is_empty());
+}
+
+#[test]
+fn added_automation_is_reported() {
+    let old = parse_project_from_bytes(&track_xml("")).unwrap();
+    let new = parse_project_from_bytes(&track_xml(
+        "<AutomationEnvelope Id=\"0\">\
+         <EnvelopeTarget><PointeeId Value=\"123\"/></EnvelopeTarget>\
+         </AutomationEnvelope>",
+    ))
+    .unwrap();
+
+    assert_eq!(new.diff(&old), vec!["Track Filter: added automation"]);
+    assert_eq!(old.diff(&new), vec!["Track Filter: removed automation"]);
+}
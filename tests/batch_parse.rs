@@ -0,0 +1,55 @@
+//! `parse_directory`/`parse_directory_recursive` should parse every `.als`
+//! file found, capturing per-file errors instead of aborting the batch.
+
+use als_parser_rs::{parse_directory, parse_directory_recursive};
+
+fn sample_xml() -> &'static str {
+    "<Ableton><Tracks><AudioTrack><Id Value=\"1\"/>\
+     <Name><EffectiveName Value=\"Kick\"/></Name>\
+     </AudioTrack></Tracks></Ableton>"
+}
+
+#[test]
+fn parses_non_recursively_and_captures_per_file_errors() {
+    let dir = tempfile::tempdir().unwrap();
+    std::fs::write(dir.path().join("good.als"), sample_xml()).unwrap();
+    std::fs::write(dir.path().join("bad.als"), "not gzip or xml").unwrap();
+    std::fs::write(dir.path().join("ignored.txt"), "irrelevant").unwrap();
+
+    let mut results = parse_directory(dir.path());
+    results.sort_by_key(|(path, _)| path.clone());
+
+    assert_eq!(results.len(), 2);
+    assert!(results[0].0.ends_with("bad.als"));
+    assert!(results[0].1.is_err());
+    assert!(results[1].0.ends_with("good.als"));
+    assert_eq!(results[1].1.as_ref().unwrap().tracks.len(), 1);
+}
+
+#[test]
+fn recursive_variant_descends_into_subdirectories() {
+    let dir = tempfile::tempdir().unwrap();
+    std::fs::write(dir.path().join("top.als"), sample_xml()).unwrap();
+    let nested = dir.path().join("nested");
+    std::fs::create_dir(&nested).unwrap();
+    std::fs::write(nested.join("child.als"), sample_xml()).unwrap();
+
+    assert_eq!(parse_directory(dir.path()).len(), 1);
+    assert_eq!(parse_directory_recursive(dir.path()).len(), 2);
+}
+
+#[test]
+fn batches_bounded_concurrency_still_returns_every_file() {
+    // Comfortably more files than any reasonable core count, so this only
+    // passes if parsing is chunked rather than assumed to fit in one batch.
+    let file_count = 200;
+    let dir = tempfile::tempdir().unwrap();
+    for i in 0..file_count {
+        std::fs::write(dir.path().join(format!("set-{i}.als")), sample_xml()).unwrap();
+    }
+
+    let results = parse_directory(dir.path());
+
+    assert_eq!(results.len(), file_count);
+    assert!(results.iter().all(|(_, result)| result.is_ok()));
+}
@@ -0,0 +1,167 @@
+//! Granular rack-branch diffing: branches are matched by
+//! `(branch_type, effective_name)` so an added/renamed chain is reported
+//! specifically instead of collapsing into one coarse "Modified internal
+//! Rack devices" line.
+
+use als_parser_rs::{Branch, CompressorParams, DelayParams, Macro, SaturatorParams, Track, TrackType};
+
+fn branch(branch_type: &str, effective_name: &str) -> Branch {
+    let mut branch = Branch::new(branch_type);
+    branch.set_effective_name(effective_name);
+    branch
+}
+
+#[test]
+fn added_chain_is_reported_specifically() {
+    let mut old_track = Track::new("1", TrackType::Midi);
+    old_track.branches.push(branch("InstrumentBranch", "Lead"));
+
+    let mut new_track = Track::new("1", TrackType::Midi);
+    new_track.branches.push(branch("InstrumentBranch", "Lead"));
+    new_track.branches.push(branch("InstrumentBranch", "Pad"));
+
+    let changes = new_track.diff_content(&old_track);
+
+    assert_eq!(changes, vec!["Track 1: Branch added (Pad)"]);
+}
+
+#[test]
+fn renamed_chain_is_reported_specifically() {
+    let mut old_track = Track::new("1", TrackType::Midi);
+    old_track.branches.push(branch("InstrumentBranch", "Lead"));
+
+    let mut new_track = Track::new("1", TrackType::Midi);
+    new_track.branches.push(branch("InstrumentBranch", "Lead 2"));
+
+    let changes = new_track.diff_content(&old_track);
+
+    assert_eq!(changes, vec!["Track 1: Branch Lead renamed to Lead 2"]);
+}
+
+#[test]
+fn bypassing_a_device_is_the_only_change_reported() {
+    let mut old_track = Track::new("1", TrackType::Midi);
+    old_track.branches.push(branch("AudioEffectBranch", "Reverb"));
+
+    let mut new_track = Track::new("1", TrackType::Midi);
+    let mut bypassed = branch("AudioEffectBranch", "Reverb");
+    bypassed.enabled = Some(false);
+    new_track.branches.push(bypassed);
+
+    let changes = new_track.diff_content(&old_track);
+
+    assert_eq!(changes, vec!["Branch Reverb: bypassed"]);
+}
+
+#[test]
+fn re_enabling_a_device_is_reported() {
+    let mut old_track = Track::new("1", TrackType::Midi);
+    let mut old_branch = branch("AudioEffectBranch", "Reverb");
+    old_branch.enabled = Some(false);
+    old_track.branches.push(old_branch);
+
+    let mut new_track = Track::new("1", TrackType::Midi);
+    let mut new_branch = branch("AudioEffectBranch", "Reverb");
+    new_branch.enabled = Some(true);
+    new_track.branches.push(new_branch);
+
+    let changes = new_track.diff_content(&old_track);
+
+    assert_eq!(changes, vec!["Branch Reverb: re-enabled"]);
+}
+
+#[test]
+fn macro_value_change_is_reported_by_name() {
+    let mut old_track = Track::new("1", TrackType::Midi);
+    let mut old_rack = branch("InstrumentBranch", "Bass Rack");
+    old_rack.macros.push(Macro {
+        name: "Cutoff".to_string(),
+        value: 64.0,
+    });
+    old_track.branches.push(old_rack);
+
+    let mut new_track = Track::new("1", TrackType::Midi);
+    let mut new_rack = branch("InstrumentBranch", "Bass Rack");
+    new_rack.macros.push(Macro {
+        name: "Cutoff".to_string(),
+        value: 100.0,
+    });
+    new_track.branches.push(new_rack);
+
+    let changes = new_track.diff_content(&old_track);
+
+    assert_eq!(changes, vec!["Branch Bass Rack: macro 'Cutoff' changed from 64 to 100"]);
+}
+
+#[test]
+fn absent_on_element_defaults_to_enabled() {
+    let mut old_track = Track::new("1", TrackType::Midi);
+    old_track.branches.push(branch("AudioEffectBranch", "Reverb"));
+
+    let mut new_track = Track::new("1", TrackType::Midi);
+    let mut new_branch = branch("AudioEffectBranch", "Reverb");
+    new_branch.enabled = Some(true);
+    new_track.branches.push(new_branch);
+
+    assert!(new_track.diff_content(&old_track).is_empty());
+}
+
+#[test]
+fn compressor_threshold_change_is_reported_by_name() {
+    let mut old_track = Track::new("1", TrackType::Midi);
+    let mut old_branch = branch("AudioEffectBranch", "Comp");
+    old_branch.compressor = Some(CompressorParams { threshold: -10.0, ratio: 2.0, attack: 1.0, release: 50.0 });
+    old_track.branches.push(old_branch);
+
+    let mut new_track = Track::new("1", TrackType::Midi);
+    let mut new_branch = branch("AudioEffectBranch", "Comp");
+    new_branch.compressor = Some(CompressorParams { threshold: -20.0, ratio: 2.0, attack: 1.0, release: 50.0 });
+    new_track.branches.push(new_branch);
+
+    let changes = new_track.diff_content(&old_track);
+
+    assert_eq!(changes, vec!["Branch Comp: compressor threshold changed from -10 to -20"]);
+}
+
+#[test]
+fn removing_a_branch_does_not_report_a_spurious_parameter_change_on_the_survivor() {
+    let mut old_track = Track::new("1", TrackType::Midi);
+    let mut comp_a = branch("AudioEffectBranch", "CompA");
+    comp_a.compressor = Some(CompressorParams { threshold: 10.0, ratio: 2.0, attack: 1.0, release: 50.0 });
+    let mut comp_b = branch("AudioEffectBranch", "CompB");
+    comp_b.compressor = Some(CompressorParams { threshold: 20.0, ratio: 2.0, attack: 1.0, release: 50.0 });
+    old_track.branches.push(comp_a);
+    old_track.branches.push(comp_b);
+
+    let mut new_track = Track::new("1", TrackType::Midi);
+    let mut surviving_comp_b = branch("AudioEffectBranch", "CompB");
+    surviving_comp_b.compressor = Some(CompressorParams { threshold: 20.0, ratio: 2.0, attack: 1.0, release: 50.0 });
+    new_track.branches.push(surviving_comp_b);
+
+    let changes = new_track.diff_content(&old_track);
+
+    assert_eq!(changes, vec!["Track 1: Branch removed (CompA)"]);
+    assert!(!changes.iter().any(|c| c.contains("compressor threshold changed")));
+}
+
+#[test]
+fn reordering_branches_does_not_report_spurious_delay_or_saturator_changes() {
+    let mut old_track = Track::new("1", TrackType::Midi);
+    let mut delay_a = branch("AudioEffectBranch", "DelayA");
+    delay_a.delay = Some(DelayParams { sync: true, delay_time: 0.25, feedback: 0.3 });
+    let mut sat_b = branch("AudioEffectBranch", "SatB");
+    sat_b.saturator = Some(SaturatorParams { drive: 0.5, output: 0.0 });
+    old_track.branches.push(delay_a);
+    old_track.branches.push(sat_b);
+
+    // Same two branches, same params, reversed order.
+    let mut new_track = Track::new("1", TrackType::Midi);
+    let mut sat_b_again = branch("AudioEffectBranch", "SatB");
+    sat_b_again.saturator = Some(SaturatorParams { drive: 0.5, output: 0.0 });
+    let mut delay_a_again = branch("AudioEffectBranch", "DelayA");
+    delay_a_again.delay = Some(DelayParams { sync: true, delay_time: 0.25, feedback: 0.3 });
+    new_track.branches.push(sat_b_again);
+    new_track.branches.push(delay_a_again);
+
+    assert!(new_track.diff_content(&old_track).is_empty());
+}
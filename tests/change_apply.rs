@@ -0,0 +1,50 @@
+//! `Project::apply` should be the inverse of `diff_structured`: applying the
+//! structured diff of (old, new) onto old reconstructs new, for the fields
+//! the `Change` variants cover.
+
+use als_parser_rs::{Track, TrackType};
+
+#[test]
+fn applying_a_structured_diff_reconstructs_the_new_project() {
+    let mut old = als_parser_rs::Project::new();
+    let mut kick = Track::new("1", TrackType::Audio);
+    kick.set_effective_name("Kick");
+    old.tracks.push(kick);
+    let mut snare = Track::new("2", TrackType::Audio);
+    snare.set_effective_name("Snare");
+    old.tracks.push(snare);
+
+    let mut new = als_parser_rs::Project::new();
+    let mut kick = Track::new("1", TrackType::Midi);
+    kick.set_effective_name("Wavetable");
+    new.tracks.push(kick);
+    let mut lead = Track::new("3", TrackType::Midi);
+    lead.set_effective_name("Lead");
+    new.tracks.push(lead);
+
+    let changes = new.diff_structured(&old);
+
+    let mut reconstructed = old.clone();
+    reconstructed.apply(&changes).unwrap();
+
+    assert_eq!(reconstructed.tracks.len(), new.tracks.len());
+    for track in &new.tracks {
+        let applied = reconstructed.tracks.iter().find(|t| t.id == track.id).unwrap();
+        assert_eq!(applied.effective_name, track.effective_name);
+        assert_eq!(applied.user_name, track.user_name);
+    }
+}
+
+#[test]
+fn applying_a_rename_for_a_missing_track_is_an_error() {
+    let mut project = als_parser_rs::Project::new();
+    project.tracks.push(Track::new("1", TrackType::Audio));
+
+    let changes = vec![als_parser_rs::Change::TrackRenamed {
+        id: "missing".to_string(),
+        from: None,
+        to: Some("New Name".to_string()),
+    }];
+
+    assert!(project.apply(&changes).is_err());
+}
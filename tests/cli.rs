@@ -0,0 +1,82 @@
+//! End-to-end coverage of the `als_parser_rs` CLI binary, using `assert_cmd`
+//! to exercise it as a real subprocess rather than calling `Project::diff`
+//! directly.
+
+use std::io::Write;
+
+use assert_cmd::Command;
+use predicates::prelude::*;
+
+/// Builds a minimal gzipped `.als`-shaped XML document with one named track.
+fn fixture_als(track_name: &str) -> Vec<u8> {
+    let xml = format!(
+        "<Ableton><Tracks><AudioTrack><Id Value=\"1\"/>\
+         <Name><EffectiveName Value=\"{track_name}\"/></Name>\
+         </AudioTrack></Tracks></Ableton>"
+    );
+
+    let mut gz = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::fast());
+    gz.write_all(xml.as_bytes()).unwrap();
+    gz.finish().unwrap()
+}
+
+/// Builds a minimal gzipped `.als`-shaped XML document with just a master
+/// track tempo, to exercise a change the CLI's default output only picks up
+/// by going through `Project::diff` itself.
+fn fixture_als_with_tempo(tempo: f64) -> Vec<u8> {
+    let xml = format!("<Ableton><MasterTrack><Tempo><Manual Value=\"{tempo}\"/></Tempo></MasterTrack></Ableton>");
+
+    let mut gz = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::fast());
+    gz.write_all(xml.as_bytes()).unwrap();
+    gz.finish().unwrap()
+}
+
+#[test]
+fn prints_golden_diff_for_renamed_track() {
+    let dir = tempfile::tempdir().unwrap();
+    let old_path = dir.path().join("DiffTestPre.als");
+    let new_path = dir.path().join("DiffTestPost.als");
+    std::fs::write(&old_path, fixture_als("Kick")).unwrap();
+    std::fs::write(&new_path, fixture_als("Kick 2")).unwrap();
+
+    let expected = std::fs::read_to_string("tests/fixtures/cli_golden.txt").unwrap();
+
+    Command::cargo_bin("als_parser_rs")
+        .unwrap()
+        .args([&old_path, &new_path])
+        .assert()
+        .success()
+        .stdout(predicate::str::diff(expected));
+}
+
+#[test]
+fn prints_tempo_change_reported_only_by_project_diff() {
+    let dir = tempfile::tempdir().unwrap();
+    let old_path = dir.path().join("TempoTestPre.als");
+    let new_path = dir.path().join("TempoTestPost.als");
+    std::fs::write(&old_path, fixture_als_with_tempo(120.0)).unwrap();
+    std::fs::write(&new_path, fixture_als_with_tempo(140.0)).unwrap();
+
+    Command::cargo_bin("als_parser_rs")
+        .unwrap()
+        .args([&old_path, &new_path])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Tempo changed from 120 to 140"));
+}
+
+#[test]
+fn missing_files_fail_rather_than_silently_succeeding() {
+    let dir = tempfile::tempdir().unwrap();
+
+    Command::cargo_bin("als_parser_rs")
+        .unwrap()
+        .args([dir.path().join("missing-old.als"), dir.path().join("missing-new.als")])
+        .assert()
+        .failure();
+}
+
+#[test]
+fn missing_arguments_print_usage_and_fail() {
+    Command::cargo_bin("als_parser_rs").unwrap().assert().failure().stderr(predicate::str::contains("Usage:"));
+}
@@ -0,0 +1,67 @@
+//! Per-clip `color`, `loop_start`/`loop_end`, and `start_time`, from the
+//! clip's own `Color`, `CurrentStart`/`CurrentEnd`, and (Arrangement-only)
+//! `Time` attribute.
+
+use als_parser_rs::parse_project_from_bytes;
+
+fn project_xml(track_body: &str) -> Vec<u8> {
+    format!("<Ableton><Tracks><AudioTrack><Id Value=\"1\"/>{track_body}</AudioTrack></Tracks></Ableton>").into_bytes()
+}
+
+#[test]
+fn session_clip_has_loop_bounds_but_no_start_time() {
+    let project = parse_project_from_bytes(&project_xml(
+        "<AudioClip><Name><EffectiveName Value=\"Loop\"/></Name>\
+         <CurrentStart Value=\"1\"/><CurrentEnd Value=\"5\"/><Color Value=\"3\"/></AudioClip>",
+    ))
+    .unwrap();
+
+    let clip = &project.tracks[0].clips[0];
+    assert_eq!(clip.start_time, None);
+    assert_eq!(clip.loop_start, Some(1.0));
+    assert_eq!(clip.loop_end, Some(5.0));
+    assert_eq!(clip.color, Some(3));
+}
+
+#[test]
+fn arrangement_clip_has_start_time_and_loop_bounds() {
+    let project = parse_project_from_bytes(&project_xml(
+        "<ArrangementClips>\
+           <MidiClip Time=\"16\"><CurrentStart Value=\"0\"/><CurrentEnd Value=\"4\"/><Color Value=\"7\"/></MidiClip>\
+         </ArrangementClips>",
+    ))
+    .unwrap();
+
+    let clip = &project.tracks[0].clips[0];
+    assert_eq!(clip.start_time, Some(16.0));
+    assert_eq!(clip.loop_start, Some(0.0));
+    assert_eq!(clip.loop_end, Some(4.0));
+    assert_eq!(clip.color, Some(7));
+}
+
+#[test]
+fn clip_without_color_leaves_it_unset() {
+    let project = parse_project_from_bytes(&project_xml(
+        "<AudioClip><Name><EffectiveName Value=\"Loop\"/></Name></AudioClip>",
+    ))
+    .unwrap();
+
+    assert_eq!(project.tracks[0].clips[0].color, None);
+}
+
+#[test]
+fn changed_clip_color_is_reported_by_the_diff() {
+    let old = parse_project_from_bytes(&project_xml(
+        "<AudioClip><Name><EffectiveName Value=\"Loop\"/></Name><Color Value=\"3\"/></AudioClip>",
+    ))
+    .unwrap();
+    let new = parse_project_from_bytes(&project_xml(
+        "<AudioClip><Name><EffectiveName Value=\"Loop\"/></Name><Color Value=\"9\"/></AudioClip>",
+    ))
+    .unwrap();
+
+    assert_eq!(
+        new.diff(&old),
+        vec!["Track 1: clip 'Loop' color changed from Some(3) to Some(9)"]
+    );
+}
@@ -0,0 +1,37 @@
+//! Audio clip sample rate and pitch metadata, from `SampleRef`'s
+//! `DefaultSampleRate` and the clip's `PitchCoarse`/`PitchFine`.
+
+use als_parser_rs::parse_project_from_bytes;
+
+fn audio_clip_xml(extra: &str) -> Vec<u8> {
+    format!(
+        "<Ableton><Tracks><AudioTrack><Id Value=\"1\"/>\
+         <AudioClip><Name><EffectiveName Value=\"Loop\"/></Name>{extra}</AudioClip>\
+         </AudioTrack></Tracks></Ableton>"
+    )
+    .into_bytes()
+}
+
+#[test]
+fn sample_rate_and_pitch_are_parsed() {
+    let project = parse_project_from_bytes(&audio_clip_xml(
+        "<SampleRef><DefaultSampleRate Value=\"48000\"/></SampleRef>\
+         <PitchCoarse Value=\"-12\"/><PitchFine Value=\"25\"/>",
+    ))
+    .unwrap();
+
+    let clip = &project.tracks[0].clips[0];
+    assert_eq!(clip.sample_rate, Some(48000));
+    assert_eq!(clip.pitch_coarse, Some(-12));
+    assert_eq!(clip.pitch_fine, Some(25));
+}
+
+#[test]
+fn clip_with_no_sample_metadata_parses_cleanly_to_none() {
+    let project = parse_project_from_bytes(&audio_clip_xml("")).unwrap();
+
+    let clip = &project.tracks[0].clips[0];
+    assert_eq!(clip.sample_rate, None);
+    assert_eq!(clip.pitch_coarse, None);
+    assert_eq!(clip.pitch_fine, None);
+}
@@ -0,0 +1,44 @@
+//! Crossfader A/B assignment, from the mixer's `CrossFadeState`.
+
+use als_parser_rs::{parse_project_from_bytes, CrossfadeAssign};
+
+fn track_xml(cross_fade_state: &str) -> Vec<u8> {
+    format!(
+        "<Ableton><Tracks><AudioTrack><Id Value=\"1\"/>\
+         <Name><EffectiveName Value=\"DJ Loop\"/></Name>\
+         <DeviceChain><Mixer>{cross_fade_state}</Mixer></DeviceChain>\
+         </AudioTrack></Tracks></Ableton>"
+    )
+    .into_bytes()
+}
+
+#[test]
+fn crossfade_state_zero_is_assignment_a() {
+    let project = parse_project_from_bytes(&track_xml("<CrossFadeState Value=\"0\"/>")).unwrap();
+
+    assert_eq!(project.tracks[0].crossfade, Some(CrossfadeAssign::A));
+}
+
+#[test]
+fn crossfade_state_one_is_no_assignment() {
+    let project = parse_project_from_bytes(&track_xml("<CrossFadeState Value=\"1\"/>")).unwrap();
+
+    assert_eq!(project.tracks[0].crossfade, None);
+}
+
+#[test]
+fn missing_element_is_no_assignment() {
+    let project = parse_project_from_bytes(&track_xml("")).unwrap();
+
+    assert_eq!(project.tracks[0].crossfade, None);
+}
+
+#[test]
+fn crossfade_assignment_change_is_reported() {
+    let old = parse_project_from_bytes(&track_xml("")).unwrap();
+    let new = parse_project_from_bytes(&track_xml("<CrossFadeState Value=\"0\"/>")).unwrap();
+
+    let changes = new.diff(&old);
+
+    assert_eq!(changes, vec!["Track DJ Loop: crossfade assignment changed from None to A"]);
+}
@@ -0,0 +1,85 @@
+//! `diff_content` must not blow the stack on a pathologically deep chain of
+//! nested racks, and should report branches beyond the configured depth as
+//! too deep to diff rather than silently truncating them. This includes the
+//! compressor/delay/saturator parameter checks, which run as part of the
+//! same matched-pair traversal as everything else here, not a separate
+//! unbounded recursion.
+
+use als_parser_rs::{Branch, CompressorParams, DelayParams, Project, SaturatorParams, Track, TrackType};
+
+fn nest(depth: usize, effective_name: &str) -> Branch {
+    let mut branch = Branch::new("InstrumentBranch");
+    branch.set_effective_name(effective_name);
+    branch.compressor = Some(CompressorParams { threshold: -12.0, ratio: 4.0, attack: 1.0, release: 100.0 });
+    branch.delay = Some(DelayParams { sync: true, delay_time: 0.25, feedback: 0.3 });
+    branch.saturator = Some(SaturatorParams { drive: 0.5, output: 0.0 });
+    if depth > 0 {
+        branch.branches.push(nest(depth - 1, effective_name));
+    }
+    branch
+}
+
+#[test]
+fn hundred_level_nesting_diffs_without_overflowing_the_stack() {
+    let mut old_track = Track::new("1", TrackType::Midi);
+    old_track.branches.push(nest(100, "Rack"));
+
+    let mut new_track = Track::new("1", TrackType::Midi);
+    new_track.branches.push(nest(100, "Rack"));
+
+    let changes = new_track.diff_content(&old_track);
+
+    assert!(changes.iter().any(|c| c.contains("nesting too deep to diff")));
+}
+
+#[test]
+fn compressor_delay_saturator_checks_respect_the_depth_cap_too() {
+    let mut old_track = Track::new("1", TrackType::Midi);
+    old_track.branches.push(nest(100, "Rack"));
+
+    let mut new_track = Track::new("1", TrackType::Midi);
+    new_track.branches.push(nest(100, "Rack"));
+
+    let changes = new_track.diff_content(&old_track);
+
+    assert!(changes.iter().any(|c| c.contains("nesting too deep to diff")));
+    assert!(!changes.iter().any(|c| c.contains("compressor")));
+    assert!(!changes.iter().any(|c| c.contains("delay")));
+    assert!(!changes.iter().any(|c| c.contains("saturator")));
+}
+
+#[test]
+fn structured_diff_and_stats_do_not_overflow_the_stack_either() {
+    let mut old_track = Track::new("1", TrackType::Midi);
+    old_track.branches.push(nest(100, "Rack"));
+
+    let mut new_track = Track::new("1", TrackType::Midi);
+    new_track.branches.push(nest(100, "Renamed Rack"));
+
+    let mut old = Project::new();
+    old.tracks.push(old_track);
+
+    let mut new = Project::new();
+    new.tracks.push(new_track);
+
+    // `strip_cosmetic`, used by both `diff_structured` and `diff_stats` to
+    // decide whether a rack's contents changed, recurses just like
+    // `diff_branch_lists_at_depth` does, so the same 100-level fixture that
+    // proved the cap for `diff_content` must not blow the stack here either.
+    let _ = new.diff_structured(&old);
+    let _ = new.diff_stats(&old);
+}
+
+#[test]
+fn nesting_within_the_default_depth_diffs_normally() {
+    let mut old_track = Track::new("1", TrackType::Midi);
+    old_track.branches.push(nest(10, "Rack"));
+
+    let mut new_track = Track::new("1", TrackType::Midi);
+    new_track.branches.push(nest(10, "Renamed Rack"));
+
+    let changes = new_track.diff_content(&old_track);
+
+    assert!(changes.iter().any(|c| c.contains("renamed to")));
+    assert!(!changes.iter().any(|c| c.contains("nesting too deep to diff")));
+}
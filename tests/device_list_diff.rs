@@ -0,0 +1,36 @@
+//! Non-rack device-chain diffing: added/removed devices are reported by
+//! name, and a pure reorder (same devices, different order) is reported
+//! distinctly from an add+remove.
+
+use als_parser_rs::{Track, TrackType};
+
+fn track_with_devices(devices: &[&str]) -> Track {
+    let mut track = Track::new("1", TrackType::Midi);
+    track.set_effective_name("Lead");
+    track.devices = devices.iter().map(|d| d.to_string()).collect();
+    track
+}
+
+#[test]
+fn added_device_is_reported_by_name() {
+    let old_track = track_with_devices(&["Eq8"]);
+    let new_track = track_with_devices(&["Eq8", "Saturator"]);
+
+    assert_eq!(new_track.diff_content(&old_track), vec!["Track Lead: added Saturator"]);
+}
+
+#[test]
+fn removed_device_is_reported_by_name() {
+    let old_track = track_with_devices(&["Eq8", "Overdrive"]);
+    let new_track = track_with_devices(&["Eq8"]);
+
+    assert_eq!(new_track.diff_content(&old_track), vec!["Track Lead: removed Overdrive"]);
+}
+
+#[test]
+fn reordering_devices_without_adding_or_removing_is_reported_distinctly() {
+    let old_track = track_with_devices(&["Eq8", "Saturator"]);
+    let new_track = track_with_devices(&["Saturator", "Eq8"]);
+
+    assert_eq!(new_track.diff_content(&old_track), vec!["Track Lead: reordered devices"]);
+}
@@ -0,0 +1,37 @@
+//! `Project::diff` must produce the same line order every time it's run on
+//! the same two projects, since track matching goes through a `BTreeMap`
+//! keyed by track id rather than a hash-ordered collection.
+
+use als_parser_rs::{Project, Track, TrackType};
+
+fn sample_old() -> Project {
+    let mut project = Project::new();
+    for (id, name) in [("1", "Kick"), ("2", "Bass"), ("3", "Hats"), ("4", "Lead")] {
+        let mut track = Track::new(id, TrackType::Audio);
+        track.set_effective_name(name);
+        project.tracks.push(track);
+    }
+    project
+}
+
+fn sample_new() -> Project {
+    let mut project = Project::new();
+    for (id, name) in [("2", "Bass Guitar"), ("3", "Hats"), ("5", "Pad")] {
+        let mut track = Track::new(id, TrackType::Audio);
+        track.set_effective_name(name);
+        project.tracks.push(track);
+    }
+    project
+}
+
+#[test]
+fn repeated_diffs_on_the_same_inputs_are_identical() {
+    let old = sample_old();
+    let new = sample_new();
+
+    let first = new.diff(&old);
+    let second = new.diff(&old);
+
+    assert_eq!(first, second);
+    assert!(!first.is_empty());
+}
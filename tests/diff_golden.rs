@@ -0,0 +1,202 @@
+//! Golden-file tests for the human-readable diff output.
+//!
+//! These lock the prose format so downstream regex-based tooling (the
+//! Electron app, mainly) doesn't silently break when internal diff logic
+//! changes. The Markdown report is checked separately, in
+//! `tests/diff_markdown.rs`.
+
+use als_parser_rs::{Clip, ClipType, ClipView, Locator, Project, Track, TrackType};
+
+fn sample_old() -> Project {
+    let mut project = Project::new();
+    let mut kick = Track::new("1", TrackType::Audio);
+    kick.set_effective_name("Kick");
+    project.tracks.push(kick);
+
+    let mut bass = Track::new("2", TrackType::Midi);
+    bass.set_effective_name("Bass");
+    project.tracks.push(bass);
+
+    project
+}
+
+fn sample_new() -> Project {
+    let mut project = Project::new();
+    let mut kick = Track::new("1", TrackType::Audio);
+    kick.set_effective_name("Kick");
+    kick.set_user_name("Kick Drum");
+    project.tracks.push(kick);
+
+    let mut lead = Track::new("3", TrackType::Midi);
+    lead.set_effective_name("Lead");
+    project.tracks.push(lead);
+
+    project
+}
+
+#[test]
+fn prose_diff_matches_golden_fixture() {
+    let old = sample_old();
+    let new = sample_new();
+
+    let changes = new.diff(&old).join("\n");
+    let expected = include_str!("fixtures/diff_golden.txt").trim_end();
+
+    assert_eq!(changes, expected);
+}
+
+#[test]
+fn swapped_tracks_report_moves_without_spurious_add_remove() {
+    let mut old = Project::new();
+    let mut old_a = Track::new("1", TrackType::Audio);
+    old_a.set_effective_name("Kick");
+    old.tracks.push(old_a);
+    let mut old_b = Track::new("2", TrackType::Audio);
+    old_b.set_effective_name("Snare");
+    old.tracks.push(old_b);
+
+    let mut new = Project::new();
+    let mut new_b = Track::new("2", TrackType::Audio);
+    new_b.set_effective_name("Snare");
+    new.tracks.push(new_b);
+    let mut new_a = Track::new("1", TrackType::Audio);
+    new_a.set_effective_name("Kick");
+    new.tracks.push(new_a);
+
+    let changes = new.diff(&old);
+
+    assert_eq!(
+        changes,
+        vec![
+            "Track 2 moved from position 2 to position 1",
+            "Track 1 moved from position 1 to position 2",
+        ]
+    );
+}
+
+#[test]
+fn track_tree_nests_group_children() {
+    let mut project = Project::new();
+    let mut group = Track::new("1", TrackType::Group);
+    group.set_effective_name("Drums");
+    project.tracks.push(group);
+
+    let mut kick = Track::new("2", TrackType::Audio);
+    kick.set_effective_name("Kick");
+    kick.group_id = Some("1".to_string());
+    project.tracks.push(kick);
+
+    let mut snare = Track::new("3", TrackType::Audio);
+    snare.set_effective_name("Snare");
+    snare.group_id = Some("1".to_string());
+    project.tracks.push(snare);
+
+    let tree = project.track_tree();
+
+    assert_eq!(tree.len(), 1);
+    assert_eq!(tree[0].track.effective_name, "Drums");
+    assert_eq!(tree[0].children.len(), 2);
+    assert_eq!(tree[0].children[0].track.effective_name, "Kick");
+    assert_eq!(tree[0].children[1].track.effective_name, "Snare");
+}
+
+#[test]
+fn track_moved_into_group_is_reported() {
+    let mut old = Project::new();
+    let mut old_group = Track::new("1", TrackType::Group);
+    old_group.set_effective_name("Drums");
+    old.tracks.push(old_group);
+    let mut old_kick = Track::new("2", TrackType::Audio);
+    old_kick.set_effective_name("Kick");
+    old.tracks.push(old_kick);
+
+    let mut new = Project::new();
+    let mut new_group = Track::new("1", TrackType::Group);
+    new_group.set_effective_name("Drums");
+    new.tracks.push(new_group);
+    let mut new_kick = Track::new("2", TrackType::Audio);
+    new_kick.set_effective_name("Kick");
+    new_kick.group_id = Some("1".to_string());
+    new.tracks.push(new_kick);
+
+    let changes = new.diff(&old);
+
+    assert_eq!(changes, vec!["Track Kick: moved into group Drums"]);
+}
+
+#[test]
+fn added_and_removed_locators_are_reported() {
+    let mut old = Project::new();
+    old.locators.push(Locator {
+        time: 0.0,
+        name: "Intro".to_string(),
+    });
+
+    let mut new = Project::new();
+    new.locators.push(Locator {
+        time: 0.0,
+        name: "Intro".to_string(),
+    });
+    new.locators.push(Locator {
+        time: 64.0,
+        name: "Chorus".to_string(),
+    });
+
+    let changes = new.diff(&old);
+
+    assert_eq!(changes, vec!["Added locator 'Chorus' at 64"]);
+}
+
+#[test]
+fn added_and_removed_clips_are_reported_by_name() {
+    let mut old = Project::new();
+    let mut old_track = Track::new("1", TrackType::Midi);
+    old_track.set_effective_name("Drums");
+    let mut intro = Clip::new(ClipType::Midi, ClipView::Session);
+    intro.name = "Intro".to_string();
+    old_track.clips.push(intro);
+    old.tracks.push(old_track);
+
+    let mut new = Project::new();
+    let mut new_track = Track::new("1", TrackType::Midi);
+    new_track.set_effective_name("Drums");
+    let mut verse2 = Clip::new(ClipType::Midi, ClipView::Session);
+    verse2.name = "Verse 2".to_string();
+    new_track.clips.push(verse2);
+    new.tracks.push(new_track);
+
+    let changes = new.diff(&old);
+
+    assert_eq!(
+        changes,
+        vec!["Track Drums: removed clip Intro", "Track Drums: added clip Verse 2"]
+    );
+}
+
+#[test]
+fn simultaneous_rename_and_instrument_swap_both_report() {
+    let mut old = Project::new();
+    let mut old_track = Track::new("1", TrackType::Midi);
+    old_track.set_user_name("My Synth");
+    old_track.set_effective_name("Operator");
+    old_track.devices.push("Operator".to_string());
+    old.tracks.push(old_track);
+
+    let mut new = Project::new();
+    let mut new_track = Track::new("1", TrackType::Midi);
+    new_track.set_effective_name("Wavetable");
+    new_track.devices.push("Wavetable".to_string());
+    new.tracks.push(new_track);
+
+    let changes = new.diff(&old);
+
+    assert_eq!(
+        changes,
+        vec![
+            "Track 1: renamed from Some(\"My Synth\") to None",
+            "Track 1: Instrument swap from Operator to Wavetable",
+            "Track Wavetable: removed Operator",
+            "Track Wavetable: added Wavetable",
+        ]
+    );
+}
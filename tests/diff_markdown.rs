@@ -0,0 +1,70 @@
+//! `Project::diff_markdown` should produce a PR-review-style Markdown report:
+//! `### Added Tracks`/`### Removed Tracks`/`### Modified Tracks` sections,
+//! with a modified track's rack changes nested as sub-bullets.
+
+use als_parser_rs::{Branch, Project, Track, TrackType};
+
+#[test]
+fn mixed_diff_has_the_expected_heading_structure() {
+    let mut old = Project::new();
+    let mut snare = Track::new("1", TrackType::Audio);
+    snare.set_effective_name("Snare");
+    old.tracks.push(snare);
+
+    let mut synth = Track::new("2", TrackType::Midi);
+    synth.set_effective_name("Synth");
+    let mut rack = Branch::new("InstrumentBranch");
+    rack.effective_name = "Lead".to_string();
+    rack.enabled = Some(true);
+    synth.branches.push(rack);
+    old.tracks.push(synth);
+
+    let mut new = Project::new();
+    let mut kick = Track::new("3", TrackType::Audio);
+    kick.set_effective_name("Kick");
+    new.tracks.push(kick);
+
+    let mut synth = Track::new("2", TrackType::Midi);
+    synth.set_effective_name("Synth");
+    let mut rack = Branch::new("InstrumentBranch");
+    rack.effective_name = "Lead".to_string();
+    rack.enabled = Some(false);
+    synth.branches.push(rack);
+    new.tracks.push(synth);
+
+    let report = new.diff_markdown(&old);
+
+    assert_eq!(
+        report,
+        "### Added Tracks\n\
+         - **Kick**\n\
+         \n\
+         ### Removed Tracks\n\
+         - **Snare**\n\
+         \n\
+         ### Modified Tracks\n\
+         - **Synth**\n\
+         \u{20}\u{20}- Branch Lead: bypassed\n\
+         \n"
+    );
+}
+
+#[test]
+fn no_changes_in_a_section_reports_none() {
+    let project = Project::new();
+
+    let report = project.diff_markdown(&project);
+
+    assert_eq!(
+        report,
+        "### Added Tracks\n\
+         - _none_\n\
+         \n\
+         ### Removed Tracks\n\
+         - _none_\n\
+         \n\
+         ### Modified Tracks\n\
+         - _none_\n\
+         \n"
+    );
+}
@@ -0,0 +1,37 @@
+//! `Project::diff_stats` should tally the same `Change` variants
+//! `diff_structured` produces, without the caller having to count them.
+
+use als_parser_rs::{DiffStats, Track, TrackType};
+
+#[test]
+fn tallies_added_removed_and_renamed_tracks() {
+    let mut old = als_parser_rs::Project::new();
+    let mut kick = Track::new("1", TrackType::Audio);
+    kick.set_effective_name("Kick");
+    old.tracks.push(kick);
+    let mut snare = Track::new("2", TrackType::Audio);
+    snare.set_effective_name("Snare");
+    old.tracks.push(snare);
+
+    let mut new = als_parser_rs::Project::new();
+    let mut kick = Track::new("1", TrackType::Audio);
+    kick.set_effective_name("Kick");
+    kick.set_user_name("Kick Drum");
+    new.tracks.push(kick);
+    let mut lead = Track::new("3", TrackType::Midi);
+    lead.set_effective_name("Lead");
+    new.tracks.push(lead);
+
+    let stats = new.diff_stats(&old);
+
+    assert_eq!(
+        stats,
+        DiffStats {
+            added: 1,
+            removed: 1,
+            renamed: 1,
+            instrument_swaps: 0,
+            racks_modified: 0,
+        }
+    );
+}
@@ -0,0 +1,30 @@
+//! An empty `UserName Value=""` means the track was never actually renamed
+//! (Live writes the element anyway); it must parse to `None`, not
+//! `Some("")`, or diffs against a truly-unnamed track would report spurious
+//! rename churn.
+
+use als_parser_rs::parse_project_from_bytes;
+
+fn track_xml(user_name_attr: &str) -> Vec<u8> {
+    format!(
+        "<Ableton><Tracks><AudioTrack><Id Value=\"1\"/>\
+         <Name><EffectiveName Value=\"Kick\"/>{user_name_attr}</Name>\
+         </AudioTrack></Tracks></Ableton>"
+    )
+    .into_bytes()
+}
+
+#[test]
+fn empty_user_name_parses_to_none() {
+    let project = parse_project_from_bytes(&track_xml("<UserName Value=\"\"/>")).unwrap();
+
+    assert_eq!(project.tracks[0].user_name, None);
+}
+
+#[test]
+fn empty_user_name_matches_a_track_with_no_user_name_element() {
+    let with_empty_user_name = parse_project_from_bytes(&track_xml("<UserName Value=\"\"/>")).unwrap();
+    let without_user_name = parse_project_from_bytes(&track_xml("")).unwrap();
+
+    assert_eq!(with_empty_user_name.diff(&without_user_name), Vec::<String>::new());
+}
@@ -0,0 +1,28 @@
+//! Track names go through quick-xml's entity unescape before being stored,
+//! so `&amp;` (and other XML entities) resolve to their real characters
+//! instead of surfacing as literal escapes in names and diffs.
+
+use als_parser_rs::parse_project_from_bytes;
+
+fn track_xml(name: &str) -> Vec<u8> {
+    format!(
+        "<Ableton><Tracks><AudioTrack><Id Value=\"1\"/>\
+         <Name><EffectiveName Value=\"{name}\"/></Name></AudioTrack></Tracks></Ableton>"
+    )
+    .into_bytes()
+}
+
+#[test]
+fn ampersand_entity_is_unescaped_in_the_stored_name() {
+    let project = parse_project_from_bytes(&track_xml("Bass &amp; Drums")).unwrap();
+
+    assert_eq!(project.tracks[0].effective_name, "Bass & Drums");
+}
+
+#[test]
+fn unescaped_name_does_not_register_a_spurious_rename() {
+    let old = parse_project_from_bytes(&track_xml("Bass &amp; Drums")).unwrap();
+    let new = parse_project_from_bytes(&track_xml("Bass &amp; Drums")).unwrap();
+
+    assert!(new.tracks[0].diff_content(&old.tracks[0]).is_empty());
+}
@@ -0,0 +1,51 @@
+//! Track freeze state, from `Freeze`/`Value` or, on older saves, the mere
+//! presence of a `FreezeStart`/`FreezeEnd` pair.
+
+use als_parser_rs::parse_project_from_bytes;
+
+fn track_xml(freeze_block: &str) -> Vec<u8> {
+    format!(
+        "<Ableton><Tracks><AudioTrack><Id Value=\"1\"/>\
+         <Name><EffectiveName Value=\"Synth\"/></Name>{freeze_block}\
+         </AudioTrack></Tracks></Ableton>"
+    )
+    .into_bytes()
+}
+
+#[test]
+fn freeze_value_true_is_frozen() {
+    let project = parse_project_from_bytes(&track_xml("<Freeze Value=\"true\"/>")).unwrap();
+
+    assert_eq!(project.tracks[0].frozen, Some(true));
+}
+
+#[test]
+fn freeze_value_false_is_explicitly_unfrozen() {
+    let project = parse_project_from_bytes(&track_xml("<Freeze Value=\"false\"/>")).unwrap();
+
+    assert_eq!(project.tracks[0].frozen, Some(false));
+}
+
+#[test]
+fn missing_freeze_element_is_none() {
+    let project = parse_project_from_bytes(&track_xml("")).unwrap();
+
+    assert_eq!(project.tracks[0].frozen, None);
+}
+
+#[test]
+fn freeze_start_end_pair_is_frozen() {
+    let project =
+        parse_project_from_bytes(&track_xml("<FreezeStart Value=\"0\"/><FreezeEnd Value=\"16\"/>")).unwrap();
+
+    assert_eq!(project.tracks[0].frozen, Some(true));
+}
+
+#[test]
+fn freeze_change_is_reported_as_frozen_and_unfrozen() {
+    let unfrozen = parse_project_from_bytes(&track_xml("")).unwrap();
+    let frozen = parse_project_from_bytes(&track_xml("<Freeze Value=\"true\"/>")).unwrap();
+
+    assert_eq!(frozen.diff(&unfrozen), vec!["Track Synth: frozen"]);
+    assert_eq!(unfrozen.diff(&frozen), vec!["Track Synth: unfrozen"]);
+}
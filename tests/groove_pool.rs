@@ -0,0 +1,46 @@
+//! Project groove pool entries, from `GroovePool`/`Grooves`/`Groove`'s
+//! `Name`/`Value`.
+
+use als_parser_rs::parse_project_from_bytes;
+
+fn project_xml(groove_pool: &str) -> Vec<u8> {
+    format!("<Ableton><Tracks></Tracks>{groove_pool}</Ableton>").into_bytes()
+}
+
+#[test]
+fn grooves_are_collected_in_order() {
+    let project = parse_project_from_bytes(&project_xml(
+        "<GroovePool><Grooves>\
+         <Groove><Name Value=\"MPC Swing 62\"/></Groove>\
+         <Groove><Name Value=\"16-Swing\"/></Groove>\
+         </Grooves></GroovePool>",
+    ))
+    .unwrap();
+
+    assert_eq!(project.grooves, vec!["MPC Swing 62", "16-Swing"]);
+}
+
+#[test]
+fn absent_groove_pool_yields_empty_vec() {
+    let project = parse_project_from_bytes(&project_xml("")).unwrap();
+
+    assert!(project.grooves.is_empty());
+}
+
+#[test]
+fn empty_groove_pool_yields_empty_vec() {
+    let project = parse_project_from_bytes(&project_xml("<GroovePool><Grooves></Grooves></GroovePool>")).unwrap();
+
+    assert!(project.grooves.is_empty());
+}
+
+#[test]
+fn added_groove_is_reported() {
+    let old = parse_project_from_bytes(&project_xml("")).unwrap();
+    let new = parse_project_from_bytes(&project_xml(
+        "<GroovePool><Grooves><Groove><Name Value=\"MPC Swing 62\"/></Groove></Grooves></GroovePool>",
+    ))
+    .unwrap();
+
+    assert_eq!(new.diff(&old), vec!["Added groove 'MPC Swing 62'"]);
+}
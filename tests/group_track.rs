@@ -0,0 +1,55 @@
+//! `GroupTrack` elements (grouped sets' group rows) must parse into
+//! `Project.tracks` like any other track, not be silently dropped.
+
+use als_parser_rs::{parse_project_from_bytes, TrackType};
+
+fn group_track_xml() -> &'static [u8] {
+    b"<Ableton><Tracks>\
+      <AudioTrack><Id Value=\"1\"/><Name><EffectiveName Value=\"Kick\"/></Name></AudioTrack>\
+      <GroupTrack><Id Value=\"2\"/><Name><EffectiveName Value=\"Drums\"/></Name></GroupTrack>\
+      </Tracks></Ableton>"
+}
+
+#[test]
+fn group_track_appears_in_project_tracks() {
+    let project = parse_project_from_bytes(group_track_xml()).unwrap();
+
+    let group = project.tracks.iter().find(|t| t.effective_name == "Drums").unwrap();
+    assert_eq!(group.track_type, TrackType::Group);
+}
+
+#[test]
+fn track_tree_nests_children_under_their_group() {
+    let project = parse_project_from_bytes(
+        b"<Ableton><Tracks>\
+          <GroupTrack><Id Value=\"2\"/><Name><EffectiveName Value=\"Drums\"/></Name></GroupTrack>\
+          <AudioTrack><Id Value=\"1\"/><Name><EffectiveName Value=\"Kick\"/></Name>\
+            <TrackGroupId Value=\"2\"/></AudioTrack>\
+          </Tracks></Ableton>",
+    )
+    .unwrap();
+
+    let tree = project.track_tree();
+
+    assert_eq!(tree.len(), 1);
+    assert_eq!(tree[0].track.effective_name, "Drums");
+    assert_eq!(tree[0].children.len(), 1);
+    assert_eq!(tree[0].children[0].track.effective_name, "Kick");
+}
+
+#[test]
+fn track_tree_keeps_orphaned_group_references_at_top_level() {
+    let project = parse_project_from_bytes(
+        b"<Ableton><Tracks>\
+          <AudioTrack><Id Value=\"1\"/><Name><EffectiveName Value=\"Kick\"/></Name>\
+            <TrackGroupId Value=\"99\"/></AudioTrack>\
+          </Tracks></Ableton>",
+    )
+    .unwrap();
+
+    let tree = project.track_tree();
+
+    assert_eq!(tree.len(), 1);
+    assert_eq!(tree[0].track.effective_name, "Kick");
+    assert!(tree[0].children.is_empty());
+}
@@ -0,0 +1,94 @@
+//! `effective_name` changes are only reported as an `Instrument swap` when
+//! the underlying device identity actually changed; a pure display-name
+//! change with an identical device chain is reported as a rename instead.
+
+use als_parser_rs::{Change, Project, Track, TrackType};
+
+#[test]
+fn same_devices_is_reported_as_a_rename_not_a_swap() {
+    let mut old = Project::new();
+    let mut old_track = Track::new("1", TrackType::Midi);
+    old_track.set_effective_name("Lead Synth");
+    old_track.devices.push("Operator".to_string());
+    old.tracks.push(old_track);
+
+    let mut new = Project::new();
+    let mut new_track = Track::new("1", TrackType::Midi);
+    new_track.set_effective_name("Main Synth");
+    new_track.devices.push("Operator".to_string());
+    new.tracks.push(new_track);
+
+    assert_eq!(new.diff(&old), vec!["Track 1: renamed from Lead Synth to Main Synth"]);
+}
+
+#[test]
+fn different_devices_is_reported_as_an_instrument_swap() {
+    let mut old = Project::new();
+    let mut old_track = Track::new("1", TrackType::Midi);
+    old_track.set_effective_name("Operator");
+    old_track.devices.push("Operator".to_string());
+    old.tracks.push(old_track);
+
+    let mut new = Project::new();
+    let mut new_track = Track::new("1", TrackType::Midi);
+    new_track.set_effective_name("Wavetable");
+    new_track.devices.push("Wavetable".to_string());
+    new.tracks.push(new_track);
+
+    assert_eq!(
+        new.diff(&old),
+        vec![
+            "Track 1: Instrument swap from Operator to Wavetable",
+            "Track Wavetable: removed Operator",
+            "Track Wavetable: added Wavetable",
+        ]
+    );
+}
+
+#[test]
+fn structured_diff_reports_same_devices_as_a_rename_not_a_swap() {
+    let mut old = Project::new();
+    let mut old_track = Track::new("1", TrackType::Midi);
+    old_track.set_effective_name("Lead Synth");
+    old_track.devices.push("Operator".to_string());
+    old.tracks.push(old_track);
+
+    let mut new = Project::new();
+    let mut new_track = Track::new("1", TrackType::Midi);
+    new_track.set_effective_name("Main Synth");
+    new_track.devices.push("Operator".to_string());
+    new.tracks.push(new_track);
+
+    assert_eq!(
+        new.diff_structured(&old),
+        vec![Change::TrackRenamed {
+            id: "1".to_string(),
+            from: Some("Lead Synth".to_string()),
+            to: Some("Main Synth".to_string()),
+        }]
+    );
+}
+
+#[test]
+fn structured_diff_reports_different_devices_as_an_instrument_swap() {
+    let mut old = Project::new();
+    let mut old_track = Track::new("1", TrackType::Midi);
+    old_track.set_effective_name("Operator");
+    old_track.devices.push("Operator".to_string());
+    old.tracks.push(old_track);
+
+    let mut new = Project::new();
+    let mut new_track = Track::new("1", TrackType::Midi);
+    new_track.set_effective_name("Wavetable");
+    new_track.devices.push("Wavetable".to_string());
+    new.tracks.push(new_track);
+
+    let changes = new.diff_structured(&old);
+
+    assert!(changes.contains(&Change::InstrumentSwapped {
+        id: "1".to_string(),
+        from: "Operator".to_string(),
+        to: "Wavetable".to_string(),
+    }));
+    assert!(!changes.iter().any(|c| matches!(c, Change::TrackRenamed { .. })));
+}
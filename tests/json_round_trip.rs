@@ -0,0 +1,37 @@
+//! Round-trip guarantees for `Project`'s JSON representation: serializing and
+//! deserializing must reproduce the original project exactly, and a snapshot
+//! written to disk must load back via `Project::from_json_path`.
+
+use als_parser_rs::{parse_project_from_bytes, Project};
+
+fn sample_project_xml() -> &'static [u8] {
+    b"<Ableton><Tracks>\
+      <AudioTrack><Id Value=\"1\"/><Name><EffectiveName Value=\"Kick\"/></Name>\
+      <DeviceChain><Limiter></Limiter></DeviceChain></AudioTrack>\
+      </Tracks><MasterTrack><DeviceChain><Eq8></Eq8></DeviceChain></MasterTrack>\
+      <GroovePool><Grooves><Groove><Name Value=\"Swing\"/></Groove></Grooves></GroovePool>\
+      </Ableton>"
+}
+
+#[test]
+fn project_survives_json_serialize_deserialize_round_trip() {
+    let project = parse_project_from_bytes(sample_project_xml()).unwrap();
+
+    let json = serde_json::to_string(&project).unwrap();
+    let round_tripped: Project = serde_json::from_str(&json).unwrap();
+
+    assert_eq!(project, round_tripped);
+}
+
+#[test]
+fn from_json_path_loads_a_saved_snapshot() {
+    let project = parse_project_from_bytes(sample_project_xml()).unwrap();
+
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("snapshot.json");
+    std::fs::write(&path, serde_json::to_string(&project).unwrap()).unwrap();
+
+    let loaded = Project::from_json_path(path.to_str().unwrap()).unwrap();
+
+    assert_eq!(loaded, project);
+}
@@ -0,0 +1,75 @@
+//! The master track, parsed into `Project.master` the same way a regular
+//! track's devices/branches are parsed, but never pushed into `Project.tracks`.
+
+use als_parser_rs::{parse_project_from_bytes, TrackType};
+
+fn project_xml(master_track: &str) -> Vec<u8> {
+    format!("<Ableton><Tracks><AudioTrack><Id Value=\"1\"/></AudioTrack></Tracks>{master_track}</Ableton>")
+        .into_bytes()
+}
+
+#[test]
+fn master_track_devices_are_parsed() {
+    let project = parse_project_from_bytes(&project_xml(
+        "<MasterTrack><DeviceChain><Limiter></Limiter></DeviceChain></MasterTrack>",
+    ))
+    .unwrap();
+
+    let master = project.master.unwrap();
+    assert_eq!(master.track_type, TrackType::Master);
+    assert_eq!(master.devices, vec!["Limiter"]);
+}
+
+#[test]
+fn master_track_does_not_leak_into_tracks_vec() {
+    let project = parse_project_from_bytes(&project_xml(
+        "<MasterTrack><DeviceChain><Limiter></Limiter></DeviceChain></MasterTrack>",
+    ))
+    .unwrap();
+
+    assert_eq!(project.tracks.len(), 1);
+    assert!(project.tracks.iter().all(|t| t.track_type != TrackType::Master));
+}
+
+#[test]
+fn absent_master_track_is_none() {
+    let project = parse_project_from_bytes(&project_xml("")).unwrap();
+
+    assert!(project.master.is_none());
+}
+
+#[test]
+fn master_mixer_settings_are_parsed_like_a_regular_track() {
+    let project = parse_project_from_bytes(&project_xml(
+        "<MasterTrack><Mixer><Volume><Manual Value=\"0.5\"/></Volume>\
+         <Pan><Manual Value=\"-0.2\"/></Pan></Mixer></MasterTrack>",
+    ))
+    .unwrap();
+
+    let master = project.master.unwrap();
+    assert_eq!(master.volume, Some(0.5));
+    assert_eq!(master.pan, Some(-0.2));
+}
+
+#[test]
+fn project_tempo_and_time_signature_are_global_not_per_track() {
+    let project = parse_project_from_bytes(&project_xml(
+        "<MasterTrack><Tempo><Manual Value=\"120\"/></Tempo>\
+         <TimeSignature><RemoteableTimeSignature><Numerator Value=\"3\"/><Denominator Value=\"4\"/></RemoteableTimeSignature></TimeSignature></MasterTrack>",
+    ))
+    .unwrap();
+
+    assert_eq!(project.tempo, Some(120.0));
+    assert_eq!(project.time_signature.as_deref(), Some("3/4"));
+}
+
+#[test]
+fn added_master_device_is_reported() {
+    let old = parse_project_from_bytes(&project_xml("<MasterTrack><DeviceChain></DeviceChain></MasterTrack>")).unwrap();
+    let new = parse_project_from_bytes(&project_xml(
+        "<MasterTrack><DeviceChain><Limiter></Limiter></DeviceChain></MasterTrack>",
+    ))
+    .unwrap();
+
+    assert_eq!(new.diff(&old), vec!["Master: added Limiter"]);
+}
@@ -0,0 +1,59 @@
+//! MIDI clip note data, from `Notes`/`KeyTracks`/`KeyTrack`'s `MidiKey`
+//! paired with each `Notes`/`MidiNoteEvent` entry.
+
+use als_parser_rs::{parse_project_from_bytes, Note};
+
+fn midi_clip_xml(notes_block: &str) -> Vec<u8> {
+    format!(
+        "<Ableton><Tracks><MidiTrack><Id Value=\"1\"/>\
+         <MidiClip><Name><EffectiveName Value=\"Melody\"/></Name>{notes_block}</MidiClip>\
+         </MidiTrack></Tracks></Ableton>"
+    )
+    .into_bytes()
+}
+
+#[test]
+fn empty_clip_has_no_notes() {
+    let project = parse_project_from_bytes(&midi_clip_xml("")).unwrap();
+
+    assert!(project.tracks[0].clips[0].notes.is_empty());
+}
+
+#[test]
+fn notes_are_parsed_with_pitch_from_the_enclosing_key_track() {
+    let project = parse_project_from_bytes(&midi_clip_xml(
+        "<Notes><KeyTracks>\
+         <KeyTrack><MidiKey Value=\"60\"/><Notes>\
+         <MidiNoteEvent Time=\"0\" Duration=\"1\" Velocity=\"100\"/>\
+         <MidiNoteEvent Time=\"1\" Duration=\"0.5\" Velocity=\"90\"/>\
+         </Notes></KeyTrack>\
+         </KeyTracks></Notes>",
+    ))
+    .unwrap();
+
+    let notes = &project.tracks[0].clips[0].notes;
+    assert_eq!(
+        notes,
+        &vec![
+            Note { pitch: 60, time: 0.0, duration: 1.0, velocity: 100, mute: false },
+            Note { pitch: 60, time: 1.0, duration: 0.5, velocity: 90, mute: false },
+        ]
+    );
+}
+
+#[test]
+fn muted_note_is_reported_via_is_enabled_false() {
+    let project = parse_project_from_bytes(&midi_clip_xml(
+        "<Notes><KeyTracks>\
+         <KeyTrack><MidiKey Value=\"64\"/><Notes>\
+         <MidiNoteEvent Time=\"0\" Duration=\"1\" Velocity=\"100\" IsEnabled=\"false\"/>\
+         </Notes></KeyTrack>\
+         </KeyTracks></Notes>",
+    ))
+    .unwrap();
+
+    assert_eq!(
+        project.tracks[0].clips[0].notes,
+        vec![Note { pitch: 64, time: 0.0, duration: 1.0, velocity: 100, mute: true }]
+    );
+}
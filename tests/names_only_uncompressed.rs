@@ -0,0 +1,46 @@
+//! `parse_project_with_config`'s `names_only` fast path should handle plain,
+//! uncompressed XML the same way it handles gzipped `.als` files, the same
+//! guarantee `get_project_from_als` already gives.
+
+use std::io::Write;
+
+use als_parser_rs::{parse_project_with_config, ParseConfig};
+
+fn sample_xml() -> String {
+    "<Ableton><Tracks><AudioTrack><Id Value=\"1\"/>\
+     <Name><EffectiveName Value=\"Kick\"/></Name>\
+     </AudioTrack></Tracks></Ableton>"
+        .to_string()
+}
+
+#[test]
+fn names_only_parses_uncompressed_xml() {
+    let dir = tempfile::tempdir().unwrap();
+    let raw_path = dir.path().join("raw.als");
+    std::fs::write(&raw_path, sample_xml()).unwrap();
+
+    let config = ParseConfig { names_only: true };
+    let project = parse_project_with_config(raw_path.to_str().unwrap(), &config).unwrap();
+
+    assert_eq!(project.tracks.len(), 1);
+    assert_eq!(project.tracks[0].effective_name, "Kick");
+}
+
+#[test]
+fn names_only_parses_gzipped_and_uncompressed_to_the_same_names() {
+    let dir = tempfile::tempdir().unwrap();
+
+    let raw_path = dir.path().join("raw.als");
+    std::fs::write(&raw_path, sample_xml()).unwrap();
+
+    let gz_path = dir.path().join("gzipped.als");
+    let mut gz = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::fast());
+    gz.write_all(sample_xml().as_bytes()).unwrap();
+    std::fs::write(&gz_path, gz.finish().unwrap()).unwrap();
+
+    let config = ParseConfig { names_only: true };
+    let raw_project = parse_project_with_config(raw_path.to_str().unwrap(), &config).unwrap();
+    let gz_project = parse_project_with_config(gz_path.to_str().unwrap(), &config).unwrap();
+
+    assert_eq!(raw_project, gz_project);
+}
@@ -0,0 +1,63 @@
+//! Guards that a `Project` JSON snapshot saved before newer fields existed
+//! still deserializes cleanly (empty/`None` for anything new), so the
+//! `parse_xml` old-project path survives crate upgrades.
+
+use als_parser_rs::Project;
+
+#[test]
+fn trimmed_old_project_json_deserializes() {
+    let old_json = r#"{
+        "tracks": [
+            {
+                "id": "1",
+                "track_type": "AudioTrack",
+                "effective_name": "Kick"
+            }
+        ]
+    }"#;
+
+    let project: Project = serde_json::from_str(old_json).expect("old snapshot should still deserialize");
+
+    assert_eq!(project.tracks.len(), 1);
+    let track = &project.tracks[0];
+    assert_eq!(track.effective_name, "Kick");
+    assert_eq!(track.user_name, None);
+    assert!(track.comp_sources.is_empty());
+    assert!(track.clips.is_empty());
+    assert_eq!(project.metronome, None);
+    assert!(project.control_surfaces.is_empty());
+}
+
+#[test]
+fn old_note_json_without_mute_deserializes_as_unmuted() {
+    let old_json = r#"{
+        "tracks": [
+            {
+                "id": "1",
+                "track_type": "MidiTrack",
+                "effective_name": "Lead",
+                "clips": [
+                    {
+                        "name": "",
+                        "clip_type": "MidiClip",
+                        "view": "Session",
+                        "notes": [
+                            {
+                                "pitch": 60,
+                                "time": 0.0,
+                                "duration": 1.0,
+                                "velocity": 100
+                            }
+                        ]
+                    }
+                ]
+            }
+        ]
+    }"#;
+
+    let project: Project = serde_json::from_str(old_json).expect("old snapshot should still deserialize");
+
+    let note = &project.tracks[0].clips[0].notes[0];
+    assert_eq!(note.pitch, 60);
+    assert!(!note.mute);
+}
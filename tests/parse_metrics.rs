@@ -0,0 +1,49 @@
+//! `parse_project_with_metrics` should report accurate file-size, track
+//! count, and a populated parse duration for both gzipped and plain XML
+//! `.als` files, without changing what gets parsed.
+
+use std::io::Write;
+
+use als_parser_rs::{get_project_from_als, parse_project_with_metrics};
+
+fn sample_xml() -> String {
+    "<Ableton><Tracks><AudioTrack><Id Value=\"1\"/>\
+     <Name><EffectiveName Value=\"Kick\"/></Name></AudioTrack>\
+     <MidiTrack><Id Value=\"2\"/>\
+     <Name><EffectiveName Value=\"Bass\"/></Name></MidiTrack>\
+     </Tracks></Ableton>"
+        .to_string()
+}
+
+#[test]
+fn metrics_match_a_plain_xml_file() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("raw.als");
+    std::fs::write(&path, sample_xml()).unwrap();
+    let path = path.to_str().unwrap();
+
+    let (project, metrics) = parse_project_with_metrics(path).unwrap();
+
+    assert_eq!(project, get_project_from_als(path).unwrap());
+    assert_eq!(metrics.track_count, 2);
+    assert_eq!(metrics.compressed_bytes, sample_xml().len() as u64);
+    assert_eq!(metrics.decompressed_bytes, sample_xml().len() as u64);
+}
+
+#[test]
+fn metrics_report_decompressed_size_for_a_gzipped_file() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("gzipped.als");
+    let mut gz = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::fast());
+    gz.write_all(sample_xml().as_bytes()).unwrap();
+    let compressed = gz.finish().unwrap();
+    std::fs::write(&path, &compressed).unwrap();
+    let path = path.to_str().unwrap();
+
+    let (project, metrics) = parse_project_with_metrics(path).unwrap();
+
+    assert_eq!(project, get_project_from_als(path).unwrap());
+    assert_eq!(metrics.track_count, 2);
+    assert_eq!(metrics.compressed_bytes, compressed.len() as u64);
+    assert_eq!(metrics.decompressed_bytes, sample_xml().len() as u64);
+}
@@ -0,0 +1,48 @@
+//! `parse_project_with_options` lets a caller skip track types (and rack
+//! branches) it doesn't need, instead of parsing and discarding them.
+
+use std::io::Cursor;
+
+use als_parser_rs::{parse_project_with_options, ParseOptions, TrackType};
+
+fn sample_xml() -> &'static str {
+    "<Ableton><Tracks>\
+     <AudioTrack><Id Value=\"1\"/><Name><EffectiveName Value=\"Kick\"/></Name></AudioTrack>\
+     <MidiTrack><Id Value=\"2\"/><Name><EffectiveName Value=\"Bass\"/></Name></MidiTrack>\
+     <ReturnTrack><Id Value=\"3\"/><Name><EffectiveName Value=\"Reverb Bus\"/></Name></ReturnTrack>\
+     </Tracks></Ableton>"
+}
+
+#[test]
+fn default_options_parse_every_track_type() {
+    let project = parse_project_with_options(Cursor::new(sample_xml()), ParseOptions::default()).unwrap();
+
+    assert_eq!(project.tracks.len(), 3);
+}
+
+#[test]
+fn excluding_return_tracks_drops_them_from_the_project() {
+    let opts = ParseOptions {
+        include_return: false,
+        ..ParseOptions::default()
+    };
+
+    let project = parse_project_with_options(Cursor::new(sample_xml()), opts).unwrap();
+
+    assert_eq!(project.tracks.len(), 2);
+    assert!(!project.tracks.iter().any(|t| t.track_type == TrackType::Return));
+}
+
+#[test]
+fn excluding_audio_and_midi_keeps_only_the_return_track() {
+    let opts = ParseOptions {
+        include_audio: false,
+        include_midi: false,
+        ..ParseOptions::default()
+    };
+
+    let project = parse_project_with_options(Cursor::new(sample_xml()), opts).unwrap();
+
+    assert_eq!(project.tracks.len(), 1);
+    assert_eq!(project.tracks[0].track_type, TrackType::Return);
+}
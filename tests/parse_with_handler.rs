@@ -0,0 +1,53 @@
+//! `parse_with_handler` must handle gzipped `.als` bytes the same way
+//! `get_project_from_als` does, and surface XML errors instead of silently
+//! stopping.
+
+use std::io::Write;
+
+use als_parser_rs::{parse_with_handler, EventHandler, Track};
+
+#[derive(Default)]
+struct TrackNameCollector {
+    names: Vec<String>,
+}
+
+impl EventHandler for TrackNameCollector {
+    fn on_track(&mut self, track: &Track) {
+        self.names.push(track.effective_name.clone());
+    }
+}
+
+fn sample_xml() -> &'static str {
+    "<Ableton><Tracks><AudioTrack><Id Value=\"1\"/>\
+     <Name><EffectiveName Value=\"Kick\"/></Name>\
+     </AudioTrack></Tracks></Ableton>"
+}
+
+#[test]
+fn gzipped_bytes_are_decompressed_before_parsing() {
+    let mut gz = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::fast());
+    gz.write_all(sample_xml().as_bytes()).unwrap();
+    let compressed = gz.finish().unwrap();
+
+    let mut handler = TrackNameCollector::default();
+    parse_with_handler(std::io::Cursor::new(compressed), &mut handler).unwrap();
+
+    assert_eq!(handler.names, vec!["Kick"]);
+}
+
+#[test]
+fn plain_xml_is_also_handled() {
+    let mut handler = TrackNameCollector::default();
+    parse_with_handler(std::io::Cursor::new(sample_xml()), &mut handler).unwrap();
+
+    assert_eq!(handler.names, vec!["Kick"]);
+}
+
+#[test]
+fn malformed_input_is_reported_as_an_error_instead_of_silently_truncating() {
+    let mut handler = TrackNameCollector::default();
+
+    let result = parse_with_handler(std::io::Cursor::new(b"not xml or gzip" as &[u8]), &mut handler);
+
+    assert!(result.is_err());
+}
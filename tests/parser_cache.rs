@@ -0,0 +1,56 @@
+//! `ParserCache` should serve a cached `Project` for a path whose mtime and
+//! length haven't changed, without re-reading the file's contents.
+
+use std::fs;
+
+use als_parser_rs::ParserCache;
+
+fn sample_xml() -> &'static str {
+    "<Ableton><Tracks><AudioTrack><Id Value=\"1\"/>\
+     <Name><EffectiveName Value=\"Kick\"/></Name></AudioTrack></Tracks></Ableton>"
+}
+
+#[test]
+fn unchanged_file_hits_the_cache_instead_of_reparsing() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("set.xml");
+    fs::write(&path, sample_xml()).unwrap();
+    let path = path.to_str().unwrap();
+
+    let mut cache = ParserCache::new();
+    let first = cache.get_or_parse(path).unwrap();
+    assert_eq!(first.tracks[0].effective_name, "Kick");
+
+    let original_modified = fs::metadata(path).unwrap().modified().unwrap();
+
+    // Same length, but content that would fail to parse if actually read
+    // again — proves the second call came from the cache rather than disk.
+    let garbage = "x".repeat(sample_xml().len());
+    assert_eq!(garbage.len(), sample_xml().len());
+    fs::write(path, &garbage).unwrap();
+    fs::File::open(path).unwrap().set_modified(original_modified).unwrap();
+
+    let second = cache.get_or_parse(path).unwrap();
+    assert_eq!(second, first);
+}
+
+#[test]
+fn changed_file_is_reparsed() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("set.xml");
+    fs::write(&path, sample_xml()).unwrap();
+    let path = path.to_str().unwrap();
+
+    let mut cache = ParserCache::new();
+    cache.get_or_parse(path).unwrap();
+
+    fs::write(
+        path,
+        "<Ableton><Tracks><AudioTrack><Id Value=\"1\"/>\
+         <Name><EffectiveName Value=\"Snare\"/></Name></AudioTrack></Tracks></Ableton>",
+    )
+    .unwrap();
+
+    let reparsed = cache.get_or_parse(path).unwrap();
+    assert_eq!(reparsed.tracks[0].effective_name, "Snare");
+}
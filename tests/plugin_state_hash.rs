@@ -0,0 +1,47 @@
+//! A branch's `state_hash` lets a diff notice an internal preset tweak that
+//! left the plugin's name unchanged; it's only compared when both sides
+//! have one.
+
+use als_parser_rs::{Branch, Track, TrackType};
+
+fn branch_with_hash(effective_name: &str, state_hash: Option<u64>) -> Branch {
+    let mut branch = Branch::new("AudioEffectBranch");
+    branch.set_effective_name(effective_name);
+    branch.state_hash = state_hash;
+    branch
+}
+
+#[test]
+fn differing_hash_is_reported_as_a_preset_change() {
+    let mut old_track = Track::new("1", TrackType::Midi);
+    old_track.branches.push(branch_with_hash("Serum", Some(111)));
+
+    let mut new_track = Track::new("1", TrackType::Midi);
+    new_track.branches.push(branch_with_hash("Serum", Some(222)));
+
+    let changes = new_track.diff_content(&old_track);
+
+    assert_eq!(changes, vec!["Branch Serum: preset changed"]);
+}
+
+#[test]
+fn same_hash_reports_no_preset_change() {
+    let mut old_track = Track::new("1", TrackType::Midi);
+    old_track.branches.push(branch_with_hash("Serum", Some(111)));
+
+    let mut new_track = Track::new("1", TrackType::Midi);
+    new_track.branches.push(branch_with_hash("Serum", Some(111)));
+
+    assert!(new_track.diff_content(&old_track).is_empty());
+}
+
+#[test]
+fn missing_hash_on_either_side_is_skipped() {
+    let mut old_track = Track::new("1", TrackType::Midi);
+    old_track.branches.push(branch_with_hash("Serum", None));
+
+    let mut new_track = Track::new("1", TrackType::Midi);
+    new_track.branches.push(branch_with_hash("Serum", Some(222)));
+
+    assert!(new_track.diff_content(&old_track).is_empty());
+}
@@ -0,0 +1,186 @@
+//! Property test guarding the JSON round-trip that `parse_xml`'s old-project
+//! path depends on: any `Project` we can construct with finite float fields
+//! must survive a serialize/deserialize cycle unchanged (JSON has no NaN or
+//! infinity; serde_json writes those as `null`). Catches serde rename mismatches on
+//! nested `branches` and enum (de)serialization drift that a handful of
+//! hand-picked fixtures would miss. A same-process round trip always
+//! serializes and deserializes the same struct shape, so it can't catch a
+//! new field missing `#[serde(default)]` — that needs a fixture with the
+//! field absent entirely; see `tests/old_json_compat.rs`.
+
+use als_parser_rs::{Branch, Clip, ClipType, ClipView, CompressorParams, Note, Project, Track, TrackType};
+use proptest::prelude::*;
+
+fn arb_track_type() -> impl Strategy<Value = TrackType> {
+    prop_oneof![
+        Just(TrackType::Audio),
+        Just(TrackType::Midi),
+        Just(TrackType::Return),
+        Just(TrackType::Group),
+        Just(TrackType::Master),
+    ]
+}
+
+fn arb_clip_type() -> impl Strategy<Value = ClipType> {
+    prop_oneof![Just(ClipType::Midi), Just(ClipType::Audio), Just(ClipType::Empty)]
+}
+
+fn arb_clip_view() -> impl Strategy<Value = ClipView> {
+    prop_oneof![Just(ClipView::Session), Just(ClipView::Arrangement)]
+}
+
+fn arb_compressor() -> impl Strategy<Value = Option<CompressorParams>> {
+    proptest::option::of((-1.0e6..1.0e6f64, -1.0e6..1.0e6f64, -1.0e6..1.0e6f64, -1.0e6..1.0e6f64).prop_map(
+        |(threshold, ratio, attack, release)| CompressorParams {
+            threshold,
+            ratio,
+            attack,
+            release,
+        },
+    ))
+}
+
+fn arb_branch() -> impl Strategy<Value = Branch> {
+    let leaf = (
+        "[A-Za-z]{1,8}",
+        proptest::option::of("[A-Za-z]{1,8}"),
+        "[A-Za-z]{1,8}",
+        any::<bool>(),
+        arb_compressor(),
+    )
+        .prop_map(|(branch_type, user_name, effective_name, expanded, compressor)| Branch {
+            branch_type,
+            user_name,
+            effective_name,
+            branches: Vec::new(),
+            expanded: Some(expanded),
+            compressor,
+            receiving_note: None,
+            delay: None,
+            saturator: None,
+            enabled: None,
+            macros: Vec::new(),
+            state_hash: None,
+        });
+
+    leaf.prop_recursive(2, 4, 2, |inner| {
+        (inner.clone(), proptest::collection::vec(inner, 0..2)).prop_map(|(mut branch, children)| {
+            branch.branches = children;
+            branch
+        })
+    })
+}
+
+fn arb_note() -> impl Strategy<Value = Note> {
+    (any::<u8>(), -1.0e6..1.0e6f64, -1.0e6..1.0e6f64, any::<u8>(), any::<bool>()).prop_map(
+        |(pitch, time, duration, velocity, mute)| Note {
+            pitch,
+            time,
+            duration,
+            velocity,
+            mute,
+        },
+    )
+}
+
+fn arb_clip() -> impl Strategy<Value = Clip> {
+    (
+        "[A-Za-z]{0,8}",
+        arb_clip_type(),
+        arb_clip_view(),
+        proptest::option::of("[A-Za-z]{1,8}"),
+        proptest::collection::vec(arb_note(), 0..3),
+    )
+        .prop_map(|(name, clip_type, view, groove, notes)| Clip {
+            name,
+            clip_type,
+            view,
+            groove,
+            original_path: None,
+            current_path: None,
+            ram_mode: None,
+            hi_q: None,
+            drum_hits: Vec::new(),
+            note_pitches: Vec::new(),
+            warp: None,
+            notes,
+            sample_rate: None,
+            pitch_coarse: None,
+            pitch_fine: None,
+            start_time: None,
+            loop_start: None,
+            loop_end: None,
+            color: None,
+            original_file_size: None,
+            original_crc: None,
+        })
+}
+
+fn arb_track() -> impl Strategy<Value = Track> {
+    (
+        "[A-Za-z0-9]{1,6}",
+        arb_track_type(),
+        proptest::option::of("[A-Za-z]{1,8}"),
+        "[A-Za-z]{1,8}",
+        proptest::collection::vec(arb_branch(), 0..2),
+        proptest::collection::vec(arb_clip(), 0..3),
+    )
+        .prop_map(|(id, track_type, user_name, effective_name, branches, clips)| Track {
+            id,
+            track_type,
+            user_name,
+            effective_name,
+            branches,
+            comp_sources: Vec::new(),
+            sends_only: None,
+            group_id: None,
+            clips,
+            sends: Vec::new(),
+            pdc_enabled: None,
+            mappings: Vec::new(),
+            muted: None,
+            soloed: None,
+            armed: None,
+            color: None,
+            devices: Vec::new(),
+            volume: None,
+            pan: None,
+            output_routing: None,
+            input_routing: None,
+            crossfade: None,
+            frozen: None,
+            automated_params: Vec::new(),
+            track_delay: None,
+            delay_is_samples: None,
+            comment: None,
+        })
+}
+
+fn arb_project() -> impl Strategy<Value = Project> {
+    proptest::collection::vec(arb_track(), 0..4).prop_map(|tracks| Project {
+        tracks,
+        metronome: None,
+        count_in: None,
+        control_surfaces: Vec::new(),
+        tempo: None,
+        tempo_automated: false,
+        time_signature: None,
+        last_modified: None,
+        samples: Vec::new(),
+        creator: None,
+        locators: Vec::new(),
+        grooves: Vec::new(),
+        master: None,
+        scenes: Vec::new(),
+        length_beats: None,
+    })
+}
+
+proptest! {
+    #[test]
+    fn project_survives_json_round_trip(project in arb_project()) {
+        let json = serde_json::to_string(&project).unwrap();
+        let round_tripped: Project = serde_json::from_str(&json).unwrap();
+        prop_assert_eq!(project, round_tripped);
+    }
+}
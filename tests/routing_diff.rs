@@ -0,0 +1,35 @@
+//! Audio input/output routing targets, from `AudioOutputRouting`/
+//! `AudioInputRouting`'s `UpperDisplayString`.
+
+use als_parser_rs::parse_project_from_bytes;
+
+fn track_xml(output_target: &str) -> Vec<u8> {
+    format!(
+        "<Ableton><Tracks><AudioTrack><Id Value=\"1\"/>\
+         <Name><EffectiveName Value=\"Kick\"/></Name>\
+         <DeviceChain><AudioOutputRouting><Target Value=\"AudioOut/Master\"/>\
+         <UpperDisplayString Value=\"{output_target}\"/></AudioOutputRouting></DeviceChain>\
+         </AudioTrack></Tracks></Ableton>"
+    )
+    .into_bytes()
+}
+
+#[test]
+fn output_routing_is_parsed_from_upper_display_string() {
+    let project = parse_project_from_bytes(&track_xml("Master")).unwrap();
+
+    assert_eq!(project.tracks[0].output_routing, Some("Master".to_string()));
+}
+
+#[test]
+fn output_routing_change_is_reported() {
+    let old = parse_project_from_bytes(&track_xml("Master")).unwrap();
+    let new = parse_project_from_bytes(&track_xml("Group Drums")).unwrap();
+
+    let changes = new.diff(&old);
+
+    assert_eq!(
+        changes,
+        vec!["Track Kick: output routing changed from 'Master' to 'Group Drums'"]
+    );
+}
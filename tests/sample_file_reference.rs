@@ -0,0 +1,42 @@
+//! Audio clip sample reference metadata, from `FileRef`'s `Path`/
+//! `RelativePath` (absolute/relative path) and `OriginalFileSize`/
+//! `OriginalCrc` (for detecting a sample that changed on disk).
+
+use als_parser_rs::parse_project_from_bytes;
+
+fn audio_clip_xml(extra: &str) -> Vec<u8> {
+    format!(
+        "<Ableton><Tracks><AudioTrack><Id Value=\"1\"/>\
+         <AudioClip><Name><EffectiveName Value=\"Loop\"/></Name>{extra}</AudioClip>\
+         </AudioTrack></Tracks></Ableton>"
+    )
+    .into_bytes()
+}
+
+#[test]
+fn file_ref_path_and_integrity_fields_are_parsed() {
+    let project = parse_project_from_bytes(&audio_clip_xml(
+        "<SampleRef><FileRef>\
+           <RelativePath Value=\"Samples/kick.wav\"/>\
+           <OriginalFileSize Value=\"123456\"/>\
+           <OriginalCrc Value=\"48879\"/>\
+         </FileRef>\
+         <OriginalPath Value=\"/Users/me/Samples/kick.wav\"/>\
+         </SampleRef>",
+    ))
+    .unwrap();
+
+    let clip = &project.tracks[0].clips[0];
+    assert_eq!(clip.original_path.as_deref(), Some("/Users/me/Samples/kick.wav"));
+    assert_eq!(clip.original_file_size, Some(123456));
+    assert_eq!(clip.original_crc, Some(48879));
+}
+
+#[test]
+fn clip_with_no_file_ref_parses_cleanly_to_none() {
+    let project = parse_project_from_bytes(&audio_clip_xml("")).unwrap();
+
+    let clip = &project.tracks[0].clips[0];
+    assert_eq!(clip.original_file_size, None);
+    assert_eq!(clip.original_crc, None);
+}
@@ -0,0 +1,83 @@
+//! Session view scenes, from `Scenes`/`Scene`'s `Name`/`Value` and optional
+//! `Tempo`/`Color`.
+
+use als_parser_rs::parse_project_from_bytes;
+
+fn project_xml(scenes: &str) -> Vec<u8> {
+    format!("<Ableton><Tracks></Tracks><Scenes>{scenes}</Scenes></Ableton>").into_bytes()
+}
+
+#[test]
+fn scenes_are_collected_in_order() {
+    let project = parse_project_from_bytes(&project_xml(
+        "<Scene><Name Value=\"Intro\"/></Scene><Scene><Name Value=\"Breakdown\"/><Tempo Value=\"128\"/></Scene>",
+    ))
+    .unwrap();
+
+    assert_eq!(project.scenes.len(), 2);
+    assert_eq!(project.scenes[0].name, "Intro");
+    assert_eq!(project.scenes[0].tempo, None);
+    assert_eq!(project.scenes[1].name, "Breakdown");
+    assert_eq!(project.scenes[1].tempo, Some(128.0));
+}
+
+#[test]
+fn absent_scenes_yields_empty_vec() {
+    let project = parse_project_from_bytes(&project_xml("")).unwrap();
+
+    assert!(project.scenes.is_empty());
+}
+
+#[test]
+fn added_scene_is_reported() {
+    let old = parse_project_from_bytes(&project_xml("<Scene><Name Value=\"Intro\"/></Scene>")).unwrap();
+    let new = parse_project_from_bytes(&project_xml(
+        "<Scene><Name Value=\"Intro\"/></Scene><Scene><Name Value=\"Breakdown\"/></Scene>",
+    ))
+    .unwrap();
+
+    assert_eq!(new.diff(&old), vec!["Added scene 'Breakdown'"]);
+}
+
+#[test]
+fn removed_scene_is_reported() {
+    let old = parse_project_from_bytes(&project_xml(
+        "<Scene><Name Value=\"Intro\"/></Scene><Scene><Name Value=\"Breakdown\"/></Scene>",
+    ))
+    .unwrap();
+    let new = parse_project_from_bytes(&project_xml("<Scene><Name Value=\"Intro\"/></Scene>")).unwrap();
+
+    assert_eq!(new.diff(&old), vec!["Removed scene"]);
+}
+
+#[test]
+fn renamed_scene_is_reported_by_position() {
+    let old = parse_project_from_bytes(&project_xml("<Scene><Name Value=\"Intro\"/></Scene>")).unwrap();
+    let new = parse_project_from_bytes(&project_xml("<Scene><Name Value=\"Verse\"/></Scene>")).unwrap();
+
+    assert_eq!(new.diff(&old), vec!["Scene 1 renamed"]);
+}
+
+#[test]
+fn scene_color_is_parsed() {
+    let project = parse_project_from_bytes(&project_xml(
+        "<Scene><Name Value=\"Intro\"/><Color Value=\"5\"/></Scene>",
+    ))
+    .unwrap();
+
+    assert_eq!(project.scenes[0].color, Some(5));
+}
+
+#[test]
+fn changed_scene_color_is_reported_by_position() {
+    let old = parse_project_from_bytes(&project_xml(
+        "<Scene><Name Value=\"Intro\"/><Color Value=\"5\"/></Scene>",
+    ))
+    .unwrap();
+    let new = parse_project_from_bytes(&project_xml(
+        "<Scene><Name Value=\"Intro\"/><Color Value=\"9\"/></Scene>",
+    ))
+    .unwrap();
+
+    assert_eq!(new.diff(&old), vec!["Scene 1 color changed"]);
+}
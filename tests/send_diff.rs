@@ -0,0 +1,42 @@
+//! Return-track sends: target index plus a linear-to-dB converted amount,
+//! and the diff line reported when a send amount changes.
+
+use als_parser_rs::{Track, TrackSend, TrackType};
+
+#[test]
+fn send_amount_change_is_reported_in_db() {
+    let mut old_track = Track::new("1", TrackType::Audio);
+    old_track.set_effective_name("Vox");
+    old_track.sends.push(TrackSend {
+        target_index: 1,
+        amount_db: None,
+    });
+
+    let mut new_track = Track::new("1", TrackType::Audio);
+    new_track.set_effective_name("Vox");
+    new_track.sends.push(TrackSend {
+        target_index: 1,
+        amount_db: Some(-12.0),
+    });
+
+    let changes = new_track.diff_content(&old_track);
+
+    assert_eq!(changes, vec!["Track Vox: send to Return B changed from -inf to -12dB"]);
+}
+
+#[test]
+fn unchanged_sends_produce_no_phantom_entries() {
+    let mut old_track = Track::new("1", TrackType::Audio);
+    old_track.sends.push(TrackSend {
+        target_index: 0,
+        amount_db: Some(-6.0),
+    });
+
+    let mut new_track = Track::new("1", TrackType::Audio);
+    new_track.sends.push(TrackSend {
+        target_index: 0,
+        amount_db: Some(-6.0),
+    });
+
+    assert!(new_track.diff_content(&old_track).is_empty());
+}
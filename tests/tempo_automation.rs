@@ -0,0 +1,56 @@
+//! `Project.tempo_automated` is set when the master tempo's automation
+//! envelope carries more than one point; a single point (or none) means a
+//! static tempo.
+
+use als_parser_rs::parse_project_from_bytes;
+
+fn project_xml(tempo_block: &str) -> Vec<u8> {
+    format!(
+        "<Ableton><Tracks><AudioTrack><Id Value=\"1\"/></AudioTrack></Tracks>\
+         <MasterTrack><Tempo>{tempo_block}</Tempo></MasterTrack></Ableton>"
+    )
+    .into_bytes()
+}
+
+#[test]
+fn static_tempo_is_not_automated() {
+    let project = parse_project_from_bytes(&project_xml(
+        "<Manual Value=\"120\"/><AutomationTarget Id=\"1\"><Events/></AutomationTarget>",
+    ))
+    .unwrap();
+
+    assert!(!project.tempo_automated);
+}
+
+#[test]
+fn tempo_envelope_with_no_tempo_block_at_all_is_not_automated() {
+    let project = parse_project_from_bytes(&project_xml("<Manual Value=\"120\"/>")).unwrap();
+
+    assert!(!project.tempo_automated);
+}
+
+#[test]
+fn tempo_envelope_with_multiple_points_is_automated() {
+    let project = parse_project_from_bytes(&project_xml(
+        "<Manual Value=\"120\"/><AutomationTarget Id=\"1\">\
+         <Events><FloatEvent Time=\"0\" Value=\"120\"/><FloatEvent Time=\"4\" Value=\"140\"/></Events>\
+         </AutomationTarget>",
+    ))
+    .unwrap();
+
+    assert!(project.tempo_automated);
+}
+
+#[test]
+fn tempo_automation_added_and_removed_are_reported() {
+    let static_tempo = parse_project_from_bytes(&project_xml("<Manual Value=\"120\"/>")).unwrap();
+    let automated_tempo = parse_project_from_bytes(&project_xml(
+        "<Manual Value=\"120\"/><AutomationTarget Id=\"1\">\
+         <Events><FloatEvent Time=\"0\" Value=\"120\"/><FloatEvent Time=\"4\" Value=\"140\"/></Events>\
+         </AutomationTarget>",
+    ))
+    .unwrap();
+
+    assert_eq!(automated_tempo.diff(&static_tempo), vec!["Tempo automation added"]);
+    assert_eq!(static_tempo.diff(&automated_tempo), vec!["Tempo automation removed"]);
+}
@@ -0,0 +1,28 @@
+//! Checks that `Track::color_hex` resolves indices through the Live color
+//! palette correctly, including the out-of-range case.
+
+use als_parser_rs::{Track, TrackType};
+
+fn track_with_color(index: u8) -> Track {
+    let mut track = Track::new("1", TrackType::Audio);
+    track.color = Some(index);
+    track
+}
+
+#[test]
+fn known_indices_map_to_expected_hex() {
+    assert_eq!(track_with_color(0).color_hex(), Some("#FF4C4C".to_string()));
+    assert_eq!(track_with_color(5).color_hex(), Some("#F6F120".to_string()));
+    assert_eq!(track_with_color(69).color_hex(), Some("#7A7A7A".to_string()));
+}
+
+#[test]
+fn missing_color_has_no_hex() {
+    let track = Track::new("1", TrackType::Audio);
+    assert_eq!(track.color_hex(), None);
+}
+
+#[test]
+fn out_of_range_index_has_no_hex() {
+    assert_eq!(track_with_color(70).color_hex(), None);
+}
@@ -0,0 +1,41 @@
+//! Per-track notes, from `Annotation`/`Value`, exposed as `Track.comment`.
+//! An empty annotation is treated as unset, mirroring `Track.user_name`.
+
+use als_parser_rs::{parse_project_from_bytes, Track, TrackType};
+
+fn track_xml(extra: &str) -> Vec<u8> {
+    format!("<Ableton><Tracks><AudioTrack><Id Value=\"1\"/>{extra}</AudioTrack></Tracks></Ableton>").into_bytes()
+}
+
+#[test]
+fn annotation_is_parsed_as_the_track_comment() {
+    let project = parse_project_from_bytes(&track_xml("<Annotation Value=\"redo bass, too muddy\"/>")).unwrap();
+
+    assert_eq!(project.tracks[0].comment, Some("redo bass, too muddy".to_string()));
+}
+
+#[test]
+fn empty_annotation_is_treated_as_no_comment() {
+    let project = parse_project_from_bytes(&track_xml("<Annotation Value=\"\"/>")).unwrap();
+
+    assert_eq!(project.tracks[0].comment, None);
+}
+
+#[test]
+fn absent_annotation_is_none() {
+    let project = parse_project_from_bytes(&track_xml("")).unwrap();
+
+    assert_eq!(project.tracks[0].comment, None);
+}
+
+#[test]
+fn changed_comment_is_reported_by_the_diff() {
+    let mut old_track = Track::new("1", TrackType::Audio);
+    old_track.set_effective_name("Bass");
+    old_track.comment = Some("needs EQ".to_string());
+
+    let mut new_track = old_track.clone();
+    new_track.comment = Some("redo bass, too muddy".to_string());
+
+    assert_eq!(new_track.diff_content(&old_track), vec!["Track Bass: comment changed"]);
+}
@@ -0,0 +1,64 @@
+//! Track delay for micro-timing/phase alignment, from the mixer's
+//! `TrackDelay`/`Value`, with an `IsValueSampleBased` unit flag.
+
+use als_parser_rs::parse_project_from_bytes;
+
+fn track_xml(mixer: &str) -> Vec<u8> {
+    format!(
+        "<Ableton><Tracks><AudioTrack><Id Value=\"1\"/>\
+         <Name><EffectiveName Value=\"Snare\"/></Name>{mixer}\
+         </AudioTrack></Tracks></Ableton>"
+    )
+    .into_bytes()
+}
+
+#[test]
+fn delay_in_ms_is_parsed() {
+    let project = parse_project_from_bytes(&track_xml(
+        "<Mixer><TrackDelay><Value Value=\"-5\"/><IsValueSampleBased Value=\"false\"/></TrackDelay></Mixer>",
+    ))
+    .unwrap();
+
+    assert_eq!(project.tracks[0].track_delay, Some(-5.0));
+    assert_eq!(project.tracks[0].delay_is_samples, Some(false));
+}
+
+#[test]
+fn delay_in_samples_is_parsed() {
+    let project = parse_project_from_bytes(&track_xml(
+        "<Mixer><TrackDelay><Value Value=\"120\"/><IsValueSampleBased Value=\"true\"/></TrackDelay></Mixer>",
+    ))
+    .unwrap();
+
+    assert_eq!(project.tracks[0].track_delay, Some(120.0));
+    assert_eq!(project.tracks[0].delay_is_samples, Some(true));
+}
+
+#[test]
+fn no_track_delay_is_none() {
+    let project = parse_project_from_bytes(&track_xml("<Mixer></Mixer>")).unwrap();
+
+    assert_eq!(project.tracks[0].track_delay, None);
+}
+
+#[test]
+fn delay_change_is_reported() {
+    let old = parse_project_from_bytes(&track_xml("<Mixer></Mixer>")).unwrap();
+    let new = parse_project_from_bytes(&track_xml(
+        "<Mixer><TrackDelay><Value Value=\"-5\"/><IsValueSampleBased Value=\"false\"/></TrackDelay></Mixer>",
+    ))
+    .unwrap();
+
+    assert_eq!(new.diff(&old), vec!["Track Snare: track delay changed from 0ms to -5ms"]);
+}
+
+#[test]
+fn zero_delay_is_not_reported_as_a_change_from_absent() {
+    let absent = parse_project_from_bytes(&track_xml("<Mixer></Mixer>")).unwrap();
+    let zero = parse_project_from_bytes(&track_xml(
+        "<Mixer><TrackDelay><Value Value=\"0\"/><IsValueSampleBased Value=\"false\"/></TrackDelay></Mixer>",
+    ))
+    .unwrap();
+
+    assert!(zero.diff(&absent).is_empty());
+}
@@ -0,0 +1,28 @@
+//! Guards that a corrupt/truncated `.als` file produces an `Err` from
+//! `get_project_from_als` instead of panicking the whole process.
+
+use als_parser_rs::get_project_from_als;
+
+#[test]
+fn truncated_gzip_header_is_an_error_not_a_panic() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("broken.als");
+    // A single byte can never be a valid gzip header (which needs at least
+    // the two magic bytes), so this exercises the short-read path too.
+    std::fs::write(&path, [0x1f]).unwrap();
+
+    let result = get_project_from_als(path.to_str().unwrap());
+
+    assert!(result.is_err());
+}
+
+#[test]
+fn wrong_magic_bytes_is_an_error_not_a_panic() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("not_gzip.als");
+    std::fs::write(&path, b"not a gzip file at all").unwrap();
+
+    let result = get_project_from_als(path.to_str().unwrap());
+
+    assert!(result.is_err());
+}
@@ -0,0 +1,45 @@
+//! A file with more branch opens than closes (most commonly a truncated
+//! save) shouldn't panic or lose/misattach the unclosed branches — see
+//! `attach_leftover_branches` in `parse.rs`.
+
+use als_parser_rs::parse_project_from_bytes;
+
+#[test]
+fn truncated_mid_rack_file_recovers_the_open_branch() {
+    // Cuts off entirely inside the rack: no closing tags at all for the
+    // branch, the device chain, the track, or the document.
+    let xml = b"<Ableton><Tracks>\
+                <MidiTrack><Id Value=\"1\"/><Name><EffectiveName Value=\"Synth\"/></Name>\
+                <DeviceChain><InstrumentBranch><Name><EffectiveName Value=\"Lead\"/></Name>";
+
+    let project = parse_project_from_bytes(xml).unwrap();
+
+    assert_eq!(project.tracks.len(), 1);
+    assert_eq!(project.tracks[0].effective_name, "Synth");
+    assert_eq!(project.tracks[0].branches.len(), 1);
+    assert_eq!(project.tracks[0].branches[0].effective_name, "Lead");
+}
+
+#[test]
+fn truncated_mid_nested_rack_preserves_nesting() {
+    // A DrumBranch opened inside an InstrumentBranch, both left unclosed.
+    let xml = b"<Ableton><Tracks>\
+                <MidiTrack><Id Value=\"1\"/><Name><EffectiveName Value=\"Synth\"/></Name>\
+                <DeviceChain><InstrumentBranch><Name><EffectiveName Value=\"Rack\"/></Name>\
+                <DrumBranch><Name><EffectiveName Value=\"Kick Pad\"/></Name>";
+
+    let project = parse_project_from_bytes(xml).unwrap();
+
+    let rack = &project.tracks[0].branches[0];
+    assert_eq!(rack.effective_name, "Rack");
+    assert_eq!(rack.branches[0].effective_name, "Kick Pad");
+}
+
+#[test]
+fn leftover_branch_with_no_enclosing_track_is_an_unbalanced_xml_error() {
+    let xml = b"<Ableton><InstrumentBranch><Name><EffectiveName Value=\"Lead\"/></Name>";
+
+    let err = parse_project_from_bytes(xml).unwrap_err();
+
+    assert!(err.to_string().contains("unbalanced"));
+}
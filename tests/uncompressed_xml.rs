@@ -0,0 +1,31 @@
+//! `get_project_from_als` should handle plain, uncompressed XML the same
+//! way it handles the usual gzipped form.
+
+use std::io::Write;
+
+use als_parser_rs::get_project_from_als;
+
+fn sample_xml() -> String {
+    "<Ableton><Tracks><AudioTrack><Id Value=\"1\"/>\
+     <Name><EffectiveName Value=\"Kick\"/></Name>\
+     </AudioTrack></Tracks></Ableton>"
+        .to_string()
+}
+
+#[test]
+fn uncompressed_xml_parses_to_the_same_project_as_gzipped() {
+    let dir = tempfile::tempdir().unwrap();
+
+    let raw_path = dir.path().join("raw.als");
+    std::fs::write(&raw_path, sample_xml()).unwrap();
+
+    let gz_path = dir.path().join("gzipped.als");
+    let mut gz = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::fast());
+    gz.write_all(sample_xml().as_bytes()).unwrap();
+    std::fs::write(&gz_path, gz.finish().unwrap()).unwrap();
+
+    let raw_project = get_project_from_als(raw_path.to_str().unwrap()).unwrap();
+    let gz_project = get_project_from_als(gz_path.to_str().unwrap()).unwrap();
+
+    assert_eq!(raw_project, gz_project);
+}
@@ -0,0 +1,20 @@
+//! `visit_tracks` should see the same track names `parse_project_from_bytes`
+//! would, without ever materializing a `Project`.
+
+use als_parser_rs::visit_tracks;
+
+fn sample_xml() -> &'static [u8] {
+    b"<Ableton><Tracks>\
+      <AudioTrack><Id Value=\"1\"/><Name><EffectiveName Value=\"Kick\"/></Name></AudioTrack>\
+      <MidiTrack><Id Value=\"2\"/><Name><EffectiveName Value=\"Bass\"/></Name></MidiTrack>\
+      </Tracks></Ableton>"
+}
+
+#[test]
+fn visits_each_track_in_document_order() {
+    let mut names = Vec::new();
+
+    visit_tracks(sample_xml(), |track| names.push(track.effective_name.clone())).unwrap();
+
+    assert_eq!(names, vec!["Kick", "Bass"]);
+}
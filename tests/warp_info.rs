@@ -0,0 +1,62 @@
+//! Audio clip warp state: `IsWarped`, `WarpMode`, and the `WarpMarkers` list.
+//! MIDI clips have no warp concept at all, so `Clip::new` never gives them a
+//! [`als_parser_rs::WarpInfo`].
+
+use als_parser_rs::{parse_project_from_bytes, ClipType};
+
+fn audio_clip_xml(warp_block: &str) -> Vec<u8> {
+    format!(
+        "<Ableton><Tracks><AudioTrack><Id Value=\"1\"/>\
+         <AudioClip><Name><EffectiveName Value=\"Loop\"/></Name>{warp_block}</AudioClip>\
+         </AudioTrack></Tracks></Ableton>"
+    )
+    .into_bytes()
+}
+
+#[test]
+fn unwarped_clip_has_false_flag_and_no_markers() {
+    let project = parse_project_from_bytes(&audio_clip_xml(
+        "<IsWarped Value=\"false\"/><WarpMode Value=\"0\"/><WarpMarkers/>",
+    ))
+    .unwrap();
+
+    let clip = &project.tracks[0].clips[0];
+    assert_eq!(clip.clip_type, ClipType::Audio);
+    let warp = clip.warp.as_ref().expect("audio clips always carry warp info");
+    assert!(!warp.is_warped);
+    assert!(warp.markers.is_empty());
+}
+
+#[test]
+fn warp_markers_are_parsed_in_order() {
+    let project = parse_project_from_bytes(&audio_clip_xml(
+        "<IsWarped Value=\"true\"/><WarpMode Value=\"4\"/>\
+         <WarpMarkers>\
+         <WarpMarker SecTime=\"0\" BeatTime=\"0\"/>\
+         <WarpMarker SecTime=\"1.5\" BeatTime=\"2\"/>\
+         </WarpMarkers>",
+    ))
+    .unwrap();
+
+    let clip = &project.tracks[0].clips[0];
+    let warp = clip.warp.as_ref().unwrap();
+    assert!(warp.is_warped);
+    assert_eq!(warp.warp_mode, Some(4));
+    assert_eq!(warp.markers.len(), 2);
+    assert_eq!(warp.markers[1].sec_time, 1.5);
+    assert_eq!(warp.markers[1].beat_time, 2.0);
+}
+
+#[test]
+fn midi_clips_have_no_warp_info() {
+    let project = parse_project_from_bytes(
+        b"<Ableton><Tracks><MidiTrack><Id Value=\"1\"/>\
+          <MidiClip><Name><EffectiveName Value=\"Melody\"/></Name></MidiClip>\
+          </MidiTrack></Tracks></Ableton>",
+    )
+    .unwrap();
+
+    let clip = &project.tracks[0].clips[0];
+    assert_eq!(clip.clip_type, ClipType::Midi);
+    assert_eq!(clip.warp, None);
+}